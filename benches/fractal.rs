@@ -0,0 +1,111 @@
+#[macro_use]
+extern crate criterion;
+extern crate noise;
+
+use criterion::{black_box, Criterion};
+use noise::{
+    fractals::{Fbm, HomogenousBlender, LayerBlender, RidgedMulti},
+    generators::Perlin,
+    NoiseFn, Seedable,
+};
+
+criterion_group!(fractal, bench_fbm2, bench_fbm3, bench_ridged3);
+criterion_group!(
+    fractal_64x64,
+    bench_fbm2_64x64,
+    bench_vec_blend2_64x64,
+    bench_fbm2_f32_64x64,
+    bench_fbm3_64x64
+);
+criterion_main!(fractal, fractal_64x64);
+
+fn bench_fbm2(c: &mut Criterion) {
+    let fbm = Fbm::default();
+    c.bench_function("fbm 2d", |b| {
+        b.iter(|| NoiseFn::<_>::get(&fbm, black_box([42.0_f64, 37.0])))
+    });
+}
+
+fn bench_fbm3(c: &mut Criterion) {
+    let fbm = Fbm::default();
+    c.bench_function("fbm 3d", |b| {
+        b.iter(|| NoiseFn::<_>::get(&fbm, black_box([42.0_f64, 37.0, 26.0])))
+    });
+}
+
+fn bench_ridged3(c: &mut Criterion) {
+    let ridged = RidgedMulti::default();
+    c.bench_function("ridged multi 3d", |b| {
+        b.iter(|| NoiseFn::<_>::get(&ridged, black_box([42.0_f64, 37.0, 26.0])))
+    });
+}
+
+fn bench_fbm2_64x64(c: &mut Criterion) {
+    let fbm = Fbm::default();
+    c.bench_function("fbm 2d (64x64)", |b| {
+        b.iter(|| {
+            for y in 0i8..64 {
+                for x in 0i8..64 {
+                    black_box(NoiseFn::<_>::get(&fbm, [x as f64, y as f64]));
+                }
+            }
+        })
+    });
+}
+
+/// Evaluates the layers with the single-precision Perlin kernel. The layers are still blended in
+/// double precision.
+fn bench_fbm2_f32_64x64(c: &mut Criterion) {
+    let fbm = Fbm::default();
+    c.bench_function("fbm 2d (f32, 64x64)", |b| {
+        b.iter(|| {
+            for y in 0i8..64 {
+                for x in 0i8..64 {
+                    black_box(NoiseFn::<_, f32>::get(&fbm, [x as f32, y as f32]));
+                }
+            }
+        })
+    });
+}
+
+/// Blends the same layers as `bench_fbm2_64x64`, but collects the layer values into a `Vec` for
+/// every sample. This is how `Fractal` was evaluated before it used a stack buffer, and serves as
+/// the baseline to compare against.
+fn bench_vec_blend2_64x64(c: &mut Criterion) {
+    let layers: Vec<Perlin> = (0..Fbm::DEFAULT_LAYERS)
+        .map(|seed| Perlin::default().with_seed(seed))
+        .collect();
+    let lacunarity = noise::fractals::DEFAULT_LACUNARITY;
+    let blender = HomogenousBlender::default();
+    c.bench_function("vec blend 2d (64x64)", |b| {
+        b.iter(|| {
+            for y in 0i8..64 {
+                for x in 0i8..64 {
+                    let mut point = [x as f64, y as f64];
+                    let values: Vec<f64> = layers
+                        .iter()
+                        .map(|layer| {
+                            let v = layer.get(point);
+                            point = [point[0] * lacunarity, point[1] * lacunarity];
+                            v
+                        })
+                        .collect();
+                    black_box(blender.blend(&values));
+                }
+            }
+        })
+    });
+}
+
+fn bench_fbm3_64x64(c: &mut Criterion) {
+    let fbm = Fbm::default();
+    c.bench_function("fbm 3d (64x64)", |b| {
+        b.iter(|| {
+            for y in 0i8..64 {
+                for x in 0i8..64 {
+                    black_box(NoiseFn::<_>::get(&fbm, [x as f64, y as f64, x as f64]));
+                }
+            }
+        })
+    });
+}
@@ -3,7 +3,7 @@ extern crate criterion;
 extern crate noise;
 
 use criterion::{black_box, Criterion};
-use noise::{NoiseFn, Perlin};
+use noise::{generators::Perlin, NoiseFn};
 
 criterion_group!(perlin, bench_perlin2, bench_perlin3, bench_perlin4);
 criterion_group!(
@@ -12,7 +12,19 @@ criterion_group!(
     bench_perlin3_64x64,
     bench_perlin4_64x64
 );
-criterion_main!(perlin, perlin_64x64);
+criterion_group!(
+    perlin_f32,
+    bench_perlin2_f32,
+    bench_perlin3_f32,
+    bench_perlin4_f32
+);
+criterion_group!(
+    perlin_f32_64x64,
+    bench_perlin2_f32_64x64,
+    bench_perlin3_f32_64x64,
+    bench_perlin4_f32_64x64
+);
+criterion_main!(perlin, perlin_64x64, perlin_f32, perlin_f32_64x64);
 
 fn bench_perlin2(c: &mut Criterion) {
     let perlin = Perlin::default();
@@ -73,3 +85,63 @@ fn bench_perlin4_64x64(c: &mut Criterion) {
         })
     });
 }
+
+fn bench_perlin2_f32(c: &mut Criterion) {
+    let perlin = Perlin::default();
+    c.bench_function("perlin 2d (f32)", |b| {
+        b.iter(|| perlin.get(black_box([42.0_f32, 37.0])))
+    });
+}
+
+fn bench_perlin3_f32(c: &mut Criterion) {
+    let perlin = Perlin::default();
+    c.bench_function("perlin 3d (f32)", |b| {
+        b.iter(|| perlin.get(black_box([42.0_f32, 37.0, 26.0])))
+    });
+}
+
+fn bench_perlin4_f32(c: &mut Criterion) {
+    let perlin = Perlin::default();
+    c.bench_function("perlin 4d (f32)", |b| {
+        b.iter(|| perlin.get(black_box([42.0_f32, 37.0, 26.0, 128.0])))
+    });
+}
+
+fn bench_perlin2_f32_64x64(c: &mut Criterion) {
+    let perlin = Perlin::default();
+    c.bench_function("perlin 2d (f32, 64x64)", |b| {
+        b.iter(|| {
+            for y in 0i8..64 {
+                for x in 0i8..64 {
+                    black_box(perlin.get([x as f32, y as f32]));
+                }
+            }
+        })
+    });
+}
+
+fn bench_perlin3_f32_64x64(c: &mut Criterion) {
+    let perlin = Perlin::default();
+    c.bench_function("perlin 3d (f32, 64x64)", |b| {
+        b.iter(|| {
+            for y in 0i8..64 {
+                for x in 0i8..64 {
+                    black_box(perlin.get([x as f32, y as f32, x as f32]));
+                }
+            }
+        })
+    });
+}
+
+fn bench_perlin4_f32_64x64(c: &mut Criterion) {
+    let perlin = Perlin::default();
+    c.bench_function("perlin 4d (f32, 64x64)", |b| {
+        b.iter(|| {
+            for y in 0i8..64 {
+                for x in 0i8..64 {
+                    black_box(perlin.get([x as f32, y as f32, x as f32, y as f32]));
+                }
+            }
+        })
+    });
+}
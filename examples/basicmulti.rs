@@ -2,10 +2,10 @@
 
 extern crate noise;
 
-use noise::{utils::*, BasicMulti};
+use noise::{fractals::BasicMulti, utils::*};
 
 fn main() {
-    PlaneMapBuilder::new(&BasicMulti::new())
+    PlaneMapBuilder::new(&BasicMulti::default())
         .build()
         .write_to_file("basicmulti.png");
 }
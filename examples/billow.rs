@@ -2,10 +2,10 @@
 
 extern crate noise;
 
-use noise::{utils::*, Billow};
+use noise::{fractals::Billow, utils::*};
 
 fn main() {
-    PlaneMapBuilder::new(&Billow::new())
+    PlaneMapBuilder::new(&Billow::default())
         .build()
         .write_to_file("billow.png");
 }
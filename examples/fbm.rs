@@ -2,10 +2,10 @@
 
 extern crate noise;
 
-use noise::{utils::*, Fbm};
+use noise::{fractals::Fbm, utils::*};
 
 fn main() {
-    let fbm = Fbm::new();
+    let fbm = Fbm::default();
 
     PlaneMapBuilder::new(&fbm)
         .with_size(1000, 1000)
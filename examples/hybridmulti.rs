@@ -2,10 +2,10 @@
 
 extern crate noise;
 
-use noise::{utils::*, HybridMulti};
+use noise::{fractals::HybridMulti, utils::*};
 
 fn main() {
-    let hybrid_multi = HybridMulti::new();
+    let hybrid_multi = HybridMulti::default();
 
     PlaneMapBuilder::new(&hybrid_multi)
         .build()
@@ -2,10 +2,10 @@
 
 extern crate noise;
 
-use noise::{utils::*, RidgedMulti};
+use noise::{fractals::RidgedMulti, utils::*};
 
 fn main() {
-    let ridged_multi = RidgedMulti::new();
+    let ridged_multi = RidgedMulti::default();
 
     PlaneMapBuilder::new(&ridged_multi)
         .build()
@@ -1,7 +1,7 @@
 //! An ultra-light private math library to make our short lives easier as we
 //! implement super-complex noise stuff.
 
-use num_traits::Num;
+use num_traits::{AsPrimitive, Float, Num};
 use std::ops::{Add, Mul, Sub};
 
 pub(crate) mod interpolate;
@@ -256,17 +256,26 @@ where
 }
 
 #[inline]
-pub(crate) fn dot2(a: [f64; 2], b: [f64; 2]) -> f64 {
+pub(crate) fn dot2<T>(a: [T; 2], b: [T; 2]) -> T
+where
+    T: Copy + Add<T, Output = T> + Mul<T, Output = T>,
+{
     fold2(zip_with2(a, b, Mul::mul), Add::add)
 }
 
 #[inline]
-pub(crate) fn dot3(a: [f64; 3], b: [f64; 3]) -> f64 {
+pub(crate) fn dot3<T>(a: [T; 3], b: [T; 3]) -> T
+where
+    T: Copy + Add<T, Output = T> + Mul<T, Output = T>,
+{
     fold3(zip_with3(a, b, Mul::mul), Add::add)
 }
 
 #[inline]
-pub(crate) fn dot4(a: [f64; 4], b: [f64; 4]) -> f64 {
+pub(crate) fn dot4<T>(a: [T; 4], b: [T; 4]) -> T
+where
+    T: Copy + Add<T, Output = T> + Mul<T, Output = T>,
+{
     fold4(zip_with4(a, b, Mul::mul), Add::add)
 }
 
@@ -347,28 +356,28 @@ pub(crate) fn to_f64_4(x: [isize; 4]) -> [f64; 4] {
 
 // isize doesn't implement From<f64>
 #[inline]
-pub(crate) fn to_isize2(x: [f64; 2]) -> [isize; 2] {
-    [x[0] as isize, x[1] as isize]
+pub(crate) fn to_isize2<T: AsPrimitive<isize>>(x: [T; 2]) -> [isize; 2] {
+    [x[0].as_(), x[1].as_()]
 }
 
 #[cfg(not(target_os = "emscripten"))]
 #[inline]
-pub(crate) fn scale_shift(value: f64, n: f64) -> f64 {
-    value.abs().mul_add(n, -1.0_f64)
+pub(crate) fn scale_shift<T: Float>(value: T, n: T) -> T {
+    value.abs().mul_add(n, -T::one())
 }
 
 #[cfg(target_os = "emscripten")]
 #[inline]
-pub(crate) fn scale_shift(value: f64, n: f64) -> f64 {
-    (value.abs() * n) + -1.0_f64
+pub(crate) fn scale_shift<T: Float>(value: T, n: T) -> T {
+    (value.abs() * n) + -T::one()
 }
 
 #[inline]
-pub(crate) fn to_isize3(x: [f64; 3]) -> [isize; 3] {
-    [x[0] as isize, x[1] as isize, x[2] as isize]
+pub(crate) fn to_isize3<T: AsPrimitive<isize>>(x: [T; 3]) -> [isize; 3] {
+    [x[0].as_(), x[1].as_(), x[2].as_()]
 }
 
 #[inline]
-pub(crate) fn to_isize4(x: [f64; 4]) -> [isize; 4] {
-    [x[0] as isize, x[1] as isize, x[2] as isize, x[3] as isize]
+pub(crate) fn to_isize4<T: AsPrimitive<isize>>(x: [T; 4]) -> [isize; 4] {
+    [x[0].as_(), x[1].as_(), x[2].as_(), x[3].as_()]
 }
@@ -18,7 +18,7 @@ impl Cubic for f32 {
         assert!(*self >= 0.0, "Self was less than 0!");
         assert!(*self <= 1.0, "Self was greater than 1!");
 
-        self * self * (3.0 - (self * 2.0))
+        cubic(*self)
     }
 }
 
@@ -28,7 +28,7 @@ impl Cubic for f64 {
         assert!(*self >= 0.0, "Self was less than 0!");
         assert!(*self <= 1.0, "Self was greater than 1!");
 
-        self * self * (3.0 - (self * 2.0))
+        cubic(*self)
     }
 }
 
@@ -67,3 +67,15 @@ where
         ]
     }
 }
+
+/// Maps `x` onto the cubic S-curve, without checking that it is between 0 and 1.
+#[inline(always)]
+pub(crate) fn cubic<F: Float>(x: F) -> F {
+    x * x * (F::from(3.0).unwrap() - x * F::from(2.0).unwrap())
+}
+
+/// The first derivative of the cubic S-curve, 6x - 6x<sup>2</sup>.
+#[inline(always)]
+pub(crate) fn cubic_derivative<F: Float>(x: F) -> F {
+    F::from(6.0).unwrap() * x * (F::one() - x)
+}
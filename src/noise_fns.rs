@@ -5,6 +5,8 @@ use crate::{
     transforms::{PointTransform, Transformed, UniformScale},
 };
 
+pub use self::fluent::*;
+
 pub mod cache;
 pub mod combiners;
 pub mod fractals;
@@ -13,6 +15,8 @@ pub mod modifiers;
 pub mod selectors;
 pub mod transformers;
 
+mod fluent;
+
 /// Base trait for noise functions.
 ///
 /// A noise function is a object that calculates and outputs a value given a
@@ -26,8 +30,14 @@ pub mod transformers;
 /// * Mathematically changing the output value from another noise function
 ///     in various ways.
 /// * Combining the output values from two noise functions in various ways.
-pub trait NoiseFn<P: SamplePoint> {
-    fn get(&self, point: P) -> f64;
+///
+/// The output type defaults to `f64`. Functions which can be used in single-precision pipelines
+/// also implement `NoiseFn<[f32; N], f32>`. Only `Perlin`, `PerlinSurflet`, `WhiteNoise` and
+/// `CellNoise` compute their output in single precision. `OpenSimplex` and `SuperSimplex`
+/// convert to and from `f64` internally, and `Fractal` evaluates its layers in the output type
+/// but blends them in `f64`. Combiners and modifiers compute in the output type of their sources.
+pub trait NoiseFn<P: SamplePoint, O = f64> {
+    fn get(&self, point: P) -> O;
 
     fn transformed<T>(self, transform: T) -> Transformed<Self, T>
     where
@@ -51,13 +61,55 @@ pub trait NoiseFn<P: SamplePoint> {
     }
 }
 
-impl<'a, P: SamplePoint, M: NoiseFn<P>> NoiseFn<P> for &'a M {
+impl<'a, P: SamplePoint, O, M: NoiseFn<P, O>> NoiseFn<P, O> for &'a M {
     #[inline]
-    fn get(&self, point: P) -> f64 {
+    fn get(&self, point: P) -> O {
         M::get(*self, point)
     }
 }
 
+impl<P: SamplePoint, O, M: NoiseFn<P, O> + ?Sized> NoiseFn<P, O> for Box<M> {
+    #[inline]
+    fn get(&self, point: P) -> O {
+        M::get(self, point)
+    }
+}
+
+/// Trait for noise functions which can compute the analytical gradient of their output at a
+/// point, along with the output itself.
+///
+/// The gradient has one component per dimension of the point. It is cheaper and more accurate
+/// than estimating the gradient with finite differences.
+pub trait GradientNoiseFn<P: SamplePoint>: NoiseFn<P> {
+    fn get_with_gradient(&self, point: P) -> (f64, P);
+}
+
+impl<P: SamplePoint, M: GradientNoiseFn<P>> GradientNoiseFn<P> for &M {
+    #[inline]
+    fn get_with_gradient(&self, point: P) -> (f64, P) {
+        M::get_with_gradient(*self, point)
+    }
+}
+
+/// Trait for noise functions which can leave out detail that is finer than the spacing between
+/// samples.
+///
+/// When a noise function is sampled further apart than the size of its smallest features, those
+/// features alias into visible shimmering and moiré patterns. Given the sampling footprint,
+/// implementations fade out such detail smoothly, and usually skip computing it altogether.
+pub trait FilteredNoiseFn<P: SamplePoint>: NoiseFn<P> {
+    /// Returns the value at `point`, filtered for samples that are `filter_width` apart in the
+    /// coordinates of `point`.
+    fn get_filtered(&self, point: P, filter_width: f64) -> f64;
+}
+
+impl<P: SamplePoint, M: FilteredNoiseFn<P>> FilteredNoiseFn<P> for &M {
+    #[inline]
+    fn get_filtered(&self, point: P, filter_width: f64) -> f64 {
+        M::get_filtered(*self, point, filter_width)
+    }
+}
+
 /// Trait for functions that require a seed before generating their values
 pub trait Seedable {
     /// Set the seed for the function implementing the `Seedable` trait
@@ -1,4 +1,5 @@
 use crate::{NoiseFn, SamplePoint};
+use num_traits::Float;
 
 macro_rules! combiner {
     ($vis:vis $name:ident($combine_fn:expr)) => {
@@ -21,12 +22,12 @@ macro_rules! combiner {
             with!(pub source2: B);
         }
 
-        impl<A, B, P: SamplePoint + Clone> NoiseFn<P> for $name<A, B>
+        impl<A, B, P: SamplePoint + Clone, O: Float> NoiseFn<P, O> for $name<A, B>
         where
-            A: NoiseFn<P>,
-            B: NoiseFn<P>,
+            A: NoiseFn<P, O>,
+            B: NoiseFn<P, O>,
         {
-            fn get(&self, point: P) -> f64 {
+            fn get(&self, point: P) -> O {
                 $combine_fn(self.source1.get(point.clone()), self.source2.get(point))
             }
         }
@@ -34,7 +35,390 @@ macro_rules! combiner {
 }
 
 combiner! { pub Add(std::ops::Add::add) }
+combiner! { pub Subtract(std::ops::Sub::sub) }
 combiner! { pub Multiply(std::ops::Mul::mul) }
-combiner! { pub Power(f64::powf) }
-combiner! { pub Min(f64::min) }
-combiner! { pub Max(f64::max) }
+combiner! { pub Power(Float::powf) }
+combiner! { pub Min(Float::min) }
+combiner! { pub Max(Float::max) }
+combiner! { pub Average(average) }
+
+fn average<O: Float>(a: O, b: O) -> O {
+    (a + b) / (O::one() + O::one())
+}
+
+fn cast<O: Float>(value: f64) -> O {
+    O::from(value).unwrap()
+}
+
+/// How [`Divide`] handles a divisor whose magnitude is below its epsilon.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DivisionPolicy {
+    /// Replaces the divisor with the epsilon, keeping its sign.
+    ClampDivisor,
+    /// Outputs zero.
+    Zero,
+    /// Outputs the given value.
+    Fallback(f64),
+}
+
+/// Noise function that outputs the output value of the first source function divided by the
+/// output value of the second source function.
+///
+/// Divisors closer to zero than `epsilon` are handled according to `policy`, so the output never
+/// becomes infinite or NaN because of a small divisor. A NaN divisor is passed through instead,
+/// so the output is NaN, whatever the policy.
+pub struct Divide<A, B> {
+    /// Outputs the dividend.
+    pub source1: A,
+    /// Outputs the divisor.
+    pub source2: B,
+    /// Smallest divisor magnitude that is divided by as is.
+    pub epsilon: f64,
+    /// How divisors smaller than `epsilon` are handled.
+    pub policy: DivisionPolicy,
+}
+
+impl<A, B> Divide<A, B> {
+    pub const DEFAULT_EPSILON: f64 = 1e-6;
+    pub const DEFAULT_POLICY: DivisionPolicy = DivisionPolicy::ClampDivisor;
+
+    pub fn new(source1: A, source2: B) -> Self {
+        Self {
+            source1,
+            source2,
+            epsilon: Self::DEFAULT_EPSILON,
+            policy: Self::DEFAULT_POLICY,
+        }
+    }
+
+    with!(pub source1: A);
+    with!(pub source2: B);
+    with!(pub epsilon: f64);
+    with!(pub policy: DivisionPolicy);
+}
+
+impl<A, B, P: SamplePoint + Clone, O: Float> NoiseFn<P, O> for Divide<A, B>
+where
+    A: NoiseFn<P, O>,
+    B: NoiseFn<P, O>,
+{
+    fn get(&self, point: P) -> O {
+        let dividend = self.source1.get(point.clone());
+        let divisor = self.source2.get(point);
+        let epsilon = cast(self.epsilon);
+
+        if divisor.is_nan() || divisor.abs() >= epsilon {
+            return dividend / divisor;
+        }
+
+        match self.policy {
+            DivisionPolicy::ClampDivisor => {
+                let sign = if divisor.is_sign_negative() {
+                    -O::one()
+                } else {
+                    O::one()
+                };
+                dividend / (epsilon * sign)
+            }
+            DivisionPolicy::Zero => O::zero(),
+            DivisionPolicy::Fallback(value) => cast(value),
+        }
+    }
+}
+
+/// Noise function that outputs a constant-weight linear interpolation between the output values
+/// of two source functions.
+///
+/// A `t` of 0.0 outputs the value of `source1`, and a `t` of 1.0 outputs the value of `source2`.
+pub struct Lerp<A, B> {
+    /// Outputs a value.
+    pub source1: A,
+    /// Outputs a value.
+    pub source2: B,
+    /// Weight of `source2` in the output.
+    pub t: f64,
+}
+
+impl<A, B> Lerp<A, B> {
+    pub const DEFAULT_T: f64 = 0.5;
+
+    pub fn new(source1: A, source2: B) -> Self {
+        Self {
+            source1,
+            source2,
+            t: Self::DEFAULT_T,
+        }
+    }
+
+    with!(pub source1: A);
+    with!(pub source2: B);
+    with!(pub t: f64);
+}
+
+impl<A, B, P: SamplePoint + Clone, O: Float> NoiseFn<P, O> for Lerp<A, B>
+where
+    A: NoiseFn<P, O>,
+    B: NoiseFn<P, O>,
+{
+    fn get(&self, point: P) -> O {
+        let a = self.source1.get(point.clone());
+        let b = self.source2.get(point);
+
+        a + (b - a) * cast(self.t)
+    }
+}
+
+/// Shape of the blend between two values used by [`SmoothMin`] and [`SmoothMax`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Smoothing {
+    /// Quadratic blend which only differs from the hard minimum or maximum where the values are
+    /// less than the radius apart.
+    Polynomial,
+    /// Exponential blend which differs from the hard minimum or maximum everywhere, but by a
+    /// negligible amount once the values are several radii apart.
+    Exponential,
+}
+
+/// Smooth minimum of `a` and `b`, blended over values less than `radius` apart.
+fn smooth_min<O: Float>(a: O, b: O, radius: f64, smoothing: Smoothing) -> O {
+    if radius <= 0.0 {
+        return a.min(b);
+    }
+
+    let k = cast::<O>(radius);
+    match smoothing {
+        Smoothing::Polynomial => {
+            let h = (k - (a - b).abs()).max(O::zero()) / k;
+            a.min(b) - h * h * k * cast(0.25)
+        }
+        Smoothing::Exponential => {
+            // Offset by the hard minimum so that the exponentials cannot overflow.
+            let min = a.min(b);
+            let sum = (-(a - min) / k).exp2() + (-(b - min) / k).exp2();
+            min - sum.log2() * k
+        }
+    }
+}
+
+macro_rules! smooth_combiner {
+    ($(#[$doc:meta])* $vis:vis $name:ident($combine_fn:expr)) => {
+        $(#[$doc])*
+        $vis struct $name<A, B> {
+            /// Outputs a value.
+            pub source1: A,
+            /// Outputs a value.
+            pub source2: B,
+            /// Distance between the two output values over which they are blended. A radius of
+            /// zero or less gives a hard crease.
+            pub radius: f64,
+            /// Shape of the blend.
+            pub smoothing: Smoothing,
+        }
+
+        impl<A, B> $name<A, B> {
+            pub const DEFAULT_RADIUS: f64 = 0.25;
+            pub const DEFAULT_SMOOTHING: Smoothing = Smoothing::Polynomial;
+
+            pub fn new(source1: A, source2: B) -> Self {
+                Self {
+                    source1,
+                    source2,
+                    radius: Self::DEFAULT_RADIUS,
+                    smoothing: Self::DEFAULT_SMOOTHING,
+                }
+            }
+
+            with!(pub source1: A);
+            with!(pub source2: B);
+            with!(pub radius: f64);
+            with!(pub smoothing: Smoothing);
+        }
+
+        impl<A, B, P: SamplePoint + Clone, O: Float> NoiseFn<P, O> for $name<A, B>
+        where
+            A: NoiseFn<P, O>,
+            B: NoiseFn<P, O>,
+        {
+            fn get(&self, point: P) -> O {
+                let a = self.source1.get(point.clone());
+                let b = self.source2.get(point);
+
+                $combine_fn(a, b, self.radius, self.smoothing)
+            }
+        }
+    };
+}
+
+smooth_combiner! {
+    /// Noise function that outputs the smaller of the output values from two source functions,
+    /// blended smoothly where the two are close instead of meeting at a crease.
+    ///
+    /// The output is never larger than that of [`Min`].
+    pub SmoothMin(smooth_min)
+}
+smooth_combiner! {
+    /// Noise function that outputs the larger of the output values from two source functions,
+    /// blended smoothly where the two are close instead of meeting at a crease.
+    ///
+    /// The output is never smaller than that of [`Max`].
+    pub SmoothMax(|a: O, b: O, radius, smoothing| -smooth_min(-a, -b, radius, smoothing))
+}
+
+/// Noise function that combines the output values from two source functions with a closure.
+///
+/// This allows one-off combinations without writing a new noise function type.
+pub struct Zip<A, B, F> {
+    /// Outputs a value.
+    pub source1: A,
+    /// Outputs a value.
+    pub source2: B,
+    /// Function combining the two output values.
+    pub function: F,
+}
+
+impl<A, B, F> Zip<A, B, F> {
+    pub fn new(source1: A, source2: B, function: F) -> Self {
+        Self {
+            source1,
+            source2,
+            function,
+        }
+    }
+
+    with!(pub source1: A);
+    with!(pub source2: B);
+}
+
+impl<A, B, F, P: SamplePoint + Clone, O> NoiseFn<P, O> for Zip<A, B, F>
+where
+    A: NoiseFn<P, O>,
+    B: NoiseFn<P, O>,
+    F: Fn(O, O) -> O,
+{
+    fn get(&self, point: P) -> O {
+        (self.function)(self.source1.get(point.clone()), self.source2.get(point))
+    }
+}
+
+macro_rules! nary_combiner {
+    ($(#[$doc:meta])* $vis:vis $name:ident($identity:expr, $combine_fn:expr)) => {
+        $(#[$doc])*
+        ///
+        /// Use a `Vec<Box<dyn NoiseFn<P>>>` to combine source functions of different types.
+        $vis struct $name<T> {
+            /// Source functions whose output values are combined.
+            pub sources: Vec<T>,
+        }
+
+        impl<T> $name<T> {
+            pub fn new(sources: impl IntoIterator<Item = T>) -> Self {
+                Self {
+                    sources: sources.into_iter().collect(),
+                }
+            }
+        }
+
+        impl<T, P: SamplePoint + Clone, O: Float> NoiseFn<P, O> for $name<T>
+        where
+            T: NoiseFn<P, O>,
+        {
+            fn get(&self, point: P) -> O {
+                self.sources
+                    .iter()
+                    .map(|source| source.get(point.clone()))
+                    .fold($identity, $combine_fn)
+            }
+        }
+    };
+}
+
+nary_combiner! {
+    /// Noise function that outputs the sum of the output values of any number of source
+    /// functions, or zero if there are none.
+    pub Sum(O::zero(), std::ops::Add::add)
+}
+nary_combiner! {
+    /// Noise function that outputs the product of the output values of any number of source
+    /// functions, or one if there are none.
+    pub Product(O::one(), std::ops::Mul::mul)
+}
+nary_combiner! {
+    /// Noise function that outputs the smallest of the output values of any number of source
+    /// functions, or positive infinity if there are none.
+    pub MinOf(O::infinity(), Float::min)
+}
+nary_combiner! {
+    /// Noise function that outputs the largest of the output values of any number of source
+    /// functions, or negative infinity if there are none.
+    pub MaxOf(O::neg_infinity(), Float::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Constant;
+
+    #[test]
+    fn divide_handles_small_divisors() {
+        let point = [0.0, 0.0];
+        let divide = Divide::new(Constant::new(1.0), Constant::new(-1e-9));
+        assert_eq!(divide.get(point), -1e6);
+        assert_eq!(divide.with_policy(DivisionPolicy::Zero).get(point), 0.0);
+        let divide = Divide::new(Constant::new(1.0), Constant::new(0.0))
+            .with_policy(DivisionPolicy::Fallback(2.0));
+        assert_eq!(divide.get(point), 2.0);
+        let divide = Divide::new(Constant::new(1.0), Constant::new(4.0));
+        assert_eq!(divide.get(point), 0.25);
+        let divide = Divide::new(Constant::new(1.0), Constant::new(f64::NAN));
+        assert!(divide.get(point).is_nan());
+    }
+
+    #[test]
+    fn smooth_min_max_blend_near_crossing() {
+        let point = [0.0, 0.0];
+        for smoothing in [Smoothing::Polynomial, Smoothing::Exponential] {
+            let min =
+                SmoothMin::new(Constant::new(0.5), Constant::new(0.5)).with_smoothing(smoothing);
+            let max =
+                SmoothMax::new(Constant::new(0.5), Constant::new(0.5)).with_smoothing(smoothing);
+            assert!(min.get(point) < 0.5);
+            assert!(max.get(point) > 0.5);
+
+            let min = SmoothMin::new(Constant::new(-0.5), Constant::new(0.5))
+                .with_radius(0.1)
+                .with_smoothing(smoothing);
+            assert!((min.get(point) + 0.5).abs() < 1e-3);
+            assert_eq!(min.with_radius(0.0).get(point), -0.5);
+        }
+
+        let min = SmoothMin::new(Constant::new(0.5), Constant::new(0.5));
+        assert_eq!(min.get(point), 0.4375);
+    }
+
+    #[test]
+    fn zip_applies_closure() {
+        let zip = Zip::new(Constant::new(3.0), Constant::new(4.0), |a: f64, b: f64| {
+            a.hypot(b)
+        });
+        assert_eq!(zip.get([0.0, 0.0]), 5.0);
+    }
+
+    #[test]
+    fn nary_combiners_match_binary_nesting() {
+        let point = [1.0, 2.0, 3.0];
+        let values = [0.5, -0.25, 0.75, 0.125];
+        let constants = || values.iter().map(|&v| Constant::new(v));
+        let nested = Add::new(
+            Add::new(Constant::new(0.5), Constant::new(-0.25)),
+            Add::new(Constant::new(0.75), Constant::new(0.125)),
+        );
+        assert_eq!(Sum::new(constants()).get(point), nested.get(point));
+        assert_eq!(Product::new(constants()).get(point), -0.01171875);
+        assert_eq!(MinOf::new(constants()).get(point), -0.25);
+        assert_eq!(MaxOf::new(constants()).get(point), 0.75);
+
+        let boxed: Vec<Box<dyn NoiseFn<[f64; 3]>>> =
+            vec![Box::new(Constant::new(1.0)), Box::new(nested)];
+        assert_eq!(Sum::new(boxed).get(point), 2.125);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{
+    combiners::{Add, Max, Min, Multiply, Power, Subtract},
+    modifiers::{Abs, Clamp, Negate, ScaleBias},
+    NoiseFn, SamplePoint, Seedable,
+};
+use std::ops;
+
+/// Wrapper around a noise function which allows building noise graphs with chained method calls
+/// and arithmetic operators.
+///
+/// Every method and operator returns the combiner or modifier it builds, wrapped in `Noise` again,
+/// so that the result can be combined further. The operators accept other `Noise` values and
+/// `f64` constants on either side:
+///
+/// ```rust
+/// use noise::{
+///     fractals::{Fbm, RidgedMulti},
+///     generators::Perlin,
+///     Noise, NoiseFn,
+/// };
+///
+/// let terrain = (Noise(Perlin::default()) * 0.5 + Noise(RidgedMulti::default()) - 0.25)
+///     .clamp(-1.0, 1.0)
+///     .max(Fbm::default());
+/// let val: f64 = terrain.get([42.4, 37.7]);
+/// ```
+///
+/// `a - b` builds `Subtract<A, B>`, and arithmetic with a constant builds a `ScaleBias`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Noise<T>(pub T);
+
+#[allow(clippy::should_implement_trait)]
+impl<T> Noise<T> {
+    /// Returns the wrapped noise function.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Adds the output of `other` to the output of this function.
+    pub fn add<B>(self, other: B) -> Noise<Add<T, B>> {
+        Noise(Add::new(self.0, other))
+    }
+
+    /// Multiplies the output of this function by the output of `other`.
+    pub fn mul<B>(self, other: B) -> Noise<Multiply<T, B>> {
+        Noise(Multiply::new(self.0, other))
+    }
+
+    /// Outputs the smaller of the outputs of this function and `other`.
+    pub fn min<B>(self, other: B) -> Noise<Min<T, B>> {
+        Noise(Min::new(self.0, other))
+    }
+
+    /// Outputs the larger of the outputs of this function and `other`.
+    pub fn max<B>(self, other: B) -> Noise<Max<T, B>> {
+        Noise(Max::new(self.0, other))
+    }
+
+    /// Raises the output of this function to the power of the output of `exponent`.
+    pub fn powf<B>(self, exponent: B) -> Noise<Power<T, B>> {
+        Noise(Power::new(self.0, exponent))
+    }
+
+    /// Multiplies the output of this function by `scale`, then adds `bias`.
+    pub fn scale_bias(self, scale: f64, bias: f64) -> Noise<ScaleBias<T>> {
+        Noise(ScaleBias::new(self.0).with_scale(scale).with_bias(bias))
+    }
+
+    /// Clamps the output of this function to the given bounds.
+    pub fn clamp(self, lower_bound: f64, upper_bound: f64) -> Noise<Clamp<T>> {
+        Noise(Clamp::new(self.0).with_bounds(lower_bound, upper_bound))
+    }
+
+    /// Outputs the absolute value of the output of this function.
+    pub fn abs(self) -> Noise<Abs<T>> {
+        Noise(Abs::new(self.0))
+    }
+}
+
+impl<T, P, O> NoiseFn<P, O> for Noise<T>
+where
+    T: NoiseFn<P, O>,
+    P: SamplePoint,
+{
+    #[inline]
+    fn get(&self, point: P) -> O {
+        self.0.get(point)
+    }
+}
+
+impl<T: Seedable> Seedable for Noise<T> {
+    fn with_seed(self, seed: u32) -> Self {
+        Noise(self.0.with_seed(seed))
+    }
+
+    fn seed(&self) -> u32 {
+        self.0.seed()
+    }
+}
+
+impl<A, B> ops::Add<Noise<B>> for Noise<A> {
+    type Output = Noise<Add<A, B>>;
+
+    fn add(self, other: Noise<B>) -> Self::Output {
+        Noise(Add::new(self.0, other.0))
+    }
+}
+
+impl<A> ops::Add<f64> for Noise<A> {
+    type Output = Noise<ScaleBias<A>>;
+
+    fn add(self, other: f64) -> Self::Output {
+        self.scale_bias(1.0, other)
+    }
+}
+
+impl<B> ops::Add<Noise<B>> for f64 {
+    type Output = Noise<ScaleBias<B>>;
+
+    fn add(self, other: Noise<B>) -> Self::Output {
+        other.scale_bias(1.0, self)
+    }
+}
+
+impl<A, B> ops::Sub<Noise<B>> for Noise<A> {
+    type Output = Noise<Subtract<A, B>>;
+
+    fn sub(self, other: Noise<B>) -> Self::Output {
+        Noise(Subtract::new(self.0, other.0))
+    }
+}
+
+impl<A> ops::Sub<f64> for Noise<A> {
+    type Output = Noise<ScaleBias<A>>;
+
+    fn sub(self, other: f64) -> Self::Output {
+        self.scale_bias(1.0, -other)
+    }
+}
+
+impl<B> ops::Sub<Noise<B>> for f64 {
+    type Output = Noise<ScaleBias<B>>;
+
+    fn sub(self, other: Noise<B>) -> Self::Output {
+        other.scale_bias(-1.0, self)
+    }
+}
+
+impl<A, B> ops::Mul<Noise<B>> for Noise<A> {
+    type Output = Noise<Multiply<A, B>>;
+
+    fn mul(self, other: Noise<B>) -> Self::Output {
+        Noise(Multiply::new(self.0, other.0))
+    }
+}
+
+impl<A> ops::Mul<f64> for Noise<A> {
+    type Output = Noise<ScaleBias<A>>;
+
+    fn mul(self, other: f64) -> Self::Output {
+        self.scale_bias(other, 0.0)
+    }
+}
+
+impl<B> ops::Mul<Noise<B>> for f64 {
+    type Output = Noise<ScaleBias<B>>;
+
+    fn mul(self, other: Noise<B>) -> Self::Output {
+        other.scale_bias(self, 0.0)
+    }
+}
+
+impl<A> ops::Neg for Noise<A> {
+    type Output = Noise<Negate<A>>;
+
+    fn neg(self) -> Self::Output {
+        Noise(Negate::new(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Constant;
+
+    #[test]
+    fn operators_build_equivalent_graph() {
+        let a = Noise(Constant::new(0.5));
+        let b = Noise(Constant::new(-0.25));
+        let point = [1.0, 2.0];
+        assert_eq!((a * 2.0 + b - 1.0).get(point), -0.25);
+        assert_eq!((a - b * b).get(point), 0.4375);
+        assert_eq!((1.0 - -a).get(point), 1.5);
+        assert_eq!(a.add(b).abs().clamp(0.0, 0.2).get(point), 0.2);
+    }
+}
@@ -1,13 +1,23 @@
 use crate::{
     generators::Perlin,
     transforms::{PointTransform, UniformScale},
-    NoiseFn, SamplePoint, Seedable,
+    FilteredNoiseFn, NoiseFn, SamplePoint, Seedable,
 };
+use num_traits::{Float, ToPrimitive};
 use rand::{Rng, SeedableRng};
 
+pub use self::{gradient_fractal::*, layered::*, turbulence::*};
+
+mod gradient_fractal;
+mod layered;
+mod turbulence;
+
 pub const DEFAULT_PERSISTENCE: f64 = 0.5;
 pub const DEFAULT_ATTENUATION: f64 = 2.0;
 pub const DEFAULT_LACUNARITY: f64 = std::f64::consts::PI * 2.0 / 3.0;
+/// The most layers a `Fractal` can have. Layer values are collected into a fixed size buffer on
+/// the stack, so evaluating a fractal never allocates.
+pub const MAX_LAYERS: usize = 32;
 
 /// Structs implementing this trait can be used to combine the result of multiple noise functions.
 pub trait LayerBlender {
@@ -15,6 +25,21 @@ pub trait LayerBlender {
     /// a series of noise functions, with the first element representing data collected from the
     /// first layer of noise. There must always be at least one layer.
     fn blend(&self, layer_values: &[f64]) -> f64;
+
+    /// Returns the lowest and highest values `blend` can produce from `layers` values, assuming
+    /// each layer value is within [-1, 1]. The bounds are theoretical, so actual output usually
+    /// covers a much smaller range.
+    ///
+    /// The default implementation reports unbounded output, which `Fractal::normalized` leaves
+    /// unchanged.
+    fn bounds(&self, _layers: usize) -> (f64, f64) {
+        (f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    /// Called when the lacunarity of the fractal this blender is used in changes. Blenders whose
+    /// layer amplitudes depend on the spacing between layers override this to follow it; the
+    /// default implementation does nothing.
+    fn set_lacunarity(&mut self, _lacunarity: f64) {}
 }
 
 /// This trait is implemented for LayerBlenders that have a value indicating how much each
@@ -29,6 +54,12 @@ pub trait ModifiableAttenuation: LayerBlender {
     fn set_attenuation(&mut self, attenuation: f64);
 }
 
+/// This trait is implemented for LayerBlenders whose accumulation used to count the first layer
+/// twice. Enabling legacy accumulation reproduces that output, for presets tuned against it.
+pub trait ModifiableAccumulation: LayerBlender {
+    fn set_legacy_accumulation(&mut self, legacy_accumulation: bool);
+}
+
 /// This is basically a derive macro.
 macro_rules! impl_mp {
     ($name:ident) => {
@@ -48,36 +79,69 @@ macro_rules! impl_ma {
         }
     };
 }
+macro_rules! impl_mac {
+    ($name:ident) => {
+        impl ModifiableAccumulation for $name {
+            fn set_legacy_accumulation(&mut self, legacy_accumulation: bool) {
+                self.legacy_accumulation = legacy_accumulation;
+            }
+        }
+    };
+}
+
+/// Sum of the amplitudes of `layers` layers, where the first layer has an amplitude of `first`
+/// and each successive layer is multiplied by `persistence`.
+fn amplitude_sum(first: f64, persistence: f64, layers: usize) -> f64 {
+    let mut sum = 0.0;
+    let mut amplitude = first;
+    for _ in 0..layers {
+        sum += amplitude;
+        amplitude *= persistence;
+    }
+    sum
+}
 
 /// A blender which multiplies each successive layer by a fixed value called 'persistence'.
+///
+/// The output is Σ v<sub>i</sub> · persistence<sup>i</sup>, where v<sub>i</sub> is the value of
+/// layer i, starting at 0.
 #[derive(Clone, Copy, Debug)]
 pub struct HomogenousBlender {
     /// Multiplier for the amplitude of each successive layer of noise.
     pub persistence: f64,
+    /// Count the first layer twice, like versions before this formula was fixed did.
+    pub legacy_accumulation: bool,
 }
 
 impl HomogenousBlender {
     pub fn new(persistence: f64) -> Self {
-        Self { persistence }
+        Self {
+            persistence,
+            legacy_accumulation: false,
+        }
     }
 }
 
 impl Default for HomogenousBlender {
     fn default() -> Self {
-        Self {
-            persistence: DEFAULT_PERSISTENCE,
-        }
+        Self::new(DEFAULT_PERSISTENCE)
     }
 }
 
 impl LayerBlender for HomogenousBlender {
     fn blend(&self, layer_values: &[f64]) -> f64 {
-        debug_assert!(layer_values.len() > 0);
+        debug_assert!(!layer_values.is_empty());
         // Start with the first layer.
         let mut result = layer_values[0];
-        // Later layers will have reduced amplitude.
+        // Later layers will have reduced amplitude. The legacy accumulation adds the first layer
+        // again.
         let mut amplitude = self.persistence;
-        for value in layer_values {
+        let later_layers = if self.legacy_accumulation {
+            layer_values
+        } else {
+            &layer_values[1..]
+        };
+        for value in later_layers {
             // Add the next layer of noise.
             result += *value * amplitude;
             // Reduce the amplitude for the following layer.
@@ -85,40 +149,61 @@ impl LayerBlender for HomogenousBlender {
         }
         result
     }
+
+    fn bounds(&self, layers: usize) -> (f64, f64) {
+        let mut bound = amplitude_sum(1.0, self.persistence.abs(), layers);
+        if self.legacy_accumulation {
+            bound = 1.0 + self.persistence.abs() * bound;
+        }
+        (-bound, bound)
+    }
 }
 
 impl_mp!(HomogenousBlender);
+impl_mac!(HomogenousBlender);
 
 /// A blender which multiplies each successive layer by a fixed value called 'persistence' as well
 /// as the combined value of all the layers before it.
+///
+/// The output starts as the value of the first layer, and each following layer i adds
+/// v<sub>i</sub> · persistence<sup>i</sup> · (the output so far).
 #[derive(Clone, Copy, Debug)]
 pub struct HeterogenousBlender {
     /// Multiplier for the amplitude of each successive layer of noise.
     pub persistence: f64,
+    /// Count the first layer twice, like versions before this formula was fixed did.
+    pub legacy_accumulation: bool,
 }
 
 impl HeterogenousBlender {
     pub fn new(persistence: f64) -> Self {
-        Self { persistence }
+        Self {
+            persistence,
+            legacy_accumulation: false,
+        }
     }
 }
 
 impl Default for HeterogenousBlender {
     fn default() -> Self {
-        Self {
-            persistence: DEFAULT_PERSISTENCE,
-        }
+        Self::new(DEFAULT_PERSISTENCE)
     }
 }
 
 impl LayerBlender for HeterogenousBlender {
     fn blend(&self, layer_values: &[f64]) -> f64 {
-        debug_assert!(layer_values.len() > 0);
+        debug_assert!(!layer_values.is_empty());
         // Start with the first layer.
         let mut result = layer_values[0];
-        // Later layers will have reduced amplitude.
+        // Later layers will have reduced amplitude. The legacy accumulation adds the first layer
+        // again.
         let mut amplitude = self.persistence;
-        for value in layer_values {
+        let later_layers = if self.legacy_accumulation {
+            layer_values
+        } else {
+            &layer_values[1..]
+        };
+        for value in later_layers {
             // Add the next layer of noise.
             result += *value * amplitude * result;
             // Reduce the amplitude for the following layer.
@@ -126,14 +211,33 @@ impl LayerBlender for HeterogenousBlender {
         }
         result
     }
+
+    fn bounds(&self, layers: usize) -> (f64, f64) {
+        // Each layer can grow the magnitude of the output by at most a factor of
+        // 1 + persistence^i.
+        let first = if self.legacy_accumulation { 0 } else { 1 };
+        let mut bound = 1.0;
+        let mut amplitude = self.persistence.abs();
+        for _ in first..layers {
+            bound *= 1.0 + amplitude;
+            amplitude *= self.persistence.abs();
+        }
+        (-bound, bound)
+    }
 }
 
 impl_mp!(HeterogenousBlender);
+impl_mac!(HeterogenousBlender);
 
 /// A blender where the output of each layer is modified by
 /// an absolute-value function. Modifying the layer values in this way
 /// produces ridge-like formations.
 ///
+/// Each layer value v is turned into a ridge signal (1 - |v|)<sup>2</sup>, which is weighted by
+/// the signal of the previous layer divided by `attenuation`, clamped to [0, 1]. The weighted
+/// signals are summed with amplitudes of persistence<sup>i</sup>, and the sum s is mapped to
+/// s · 1.25 - 1.0 like libnoise.
+///
 /// The values output from this blender will usually range from -1.0 to 1.0 with
 /// default values for the parameters, but there are no guarantees that all
 /// output values will exist within this range. If the parameters are modified
@@ -148,6 +252,9 @@ pub struct RidgedBlender {
     pub attenuation: f64,
     /// Multiplier for the amplitude of each successive layer of noise.
     pub persistence: f64,
+    /// Start from the raw value of the first layer and skip the final mapping, like versions
+    /// before this formula was fixed did.
+    pub legacy_accumulation: bool,
 }
 
 impl RidgedBlender {
@@ -155,26 +262,25 @@ impl RidgedBlender {
         Self {
             attenuation,
             persistence,
+            legacy_accumulation: false,
         }
     }
 }
 
 impl Default for RidgedBlender {
     fn default() -> Self {
-        Self {
-            attenuation: DEFAULT_ATTENUATION,
-            persistence: DEFAULT_PERSISTENCE,
-        }
+        Self::new(DEFAULT_ATTENUATION, DEFAULT_PERSISTENCE)
     }
 }
 
 impl LayerBlender for RidgedBlender {
     fn blend(&self, layer_values: &[f64]) -> f64 {
-        debug_assert!(layer_values.len() > 0);
-        // Start with the first layer.
-        let mut result = layer_values[0];
-        // Later layers will have reduced amplitude.
-        let mut amplitude = self.persistence;
+        debug_assert!(!layer_values.is_empty());
+        let (mut result, mut amplitude) = if self.legacy_accumulation {
+            (layer_values[0], self.persistence)
+        } else {
+            (0.0, 1.0)
+        };
         let mut weight = 1.0;
         for value in layer_values {
             // Make the ridges.
@@ -194,12 +300,378 @@ impl LayerBlender for RidgedBlender {
             // Reduce the amplitude for the following layer.
             amplitude *= self.persistence;
         }
-        result
+        if self.legacy_accumulation {
+            result
+        } else {
+            result * 1.25 - 1.0
+        }
+    }
+
+    fn bounds(&self, layers: usize) -> (f64, f64) {
+        // The ridge signals are within [0, 1], so layers with a negative amplitude can only lower
+        // the result and layers with a positive amplitude can only raise it.
+        let (first, start) = if self.legacy_accumulation {
+            (self.persistence, (-1.0, 1.0))
+        } else {
+            (1.0, (0.0, 0.0))
+        };
+        let mut amplitude = first;
+        let (mut lower, mut upper) = start;
+        for _ in 0..layers {
+            lower += amplitude.min(0.0);
+            upper += amplitude.max(0.0);
+            amplitude *= self.persistence;
+        }
+        if self.legacy_accumulation {
+            (lower, upper)
+        } else {
+            (lower * 1.25 - 1.0, upper * 1.25 - 1.0)
+        }
     }
 }
 
 impl_mp!(RidgedBlender);
 impl_ma!(RidgedBlender);
+impl_mac!(RidgedBlender);
+
+/// A blender where the output of each layer is folded with an absolute-value function, producing
+/// "billowy" cloud or rock-like formations.
+///
+/// Each layer value is mapped from [-1, 1] onto |2v| - 1 before being added with the same
+/// amplitudes as `HomogenousBlender`. The result is shifted up by 0.5 to keep its average near
+/// zero, matching libnoise.
+#[derive(Clone, Copy, Debug)]
+pub struct BillowBlender {
+    /// Multiplier for the amplitude of each successive layer of noise.
+    pub persistence: f64,
+}
+
+impl BillowBlender {
+    pub fn new(persistence: f64) -> Self {
+        Self { persistence }
+    }
+}
+
+impl Default for BillowBlender {
+    fn default() -> Self {
+        Self {
+            persistence: DEFAULT_PERSISTENCE,
+        }
+    }
+}
+
+impl LayerBlender for BillowBlender {
+    fn blend(&self, layer_values: &[f64]) -> f64 {
+        debug_assert!(!layer_values.is_empty());
+        let mut result = 0.0;
+        let mut amplitude = 1.0;
+        for value in layer_values {
+            // Fold the signal to make the billows.
+            result += (value.abs() * 2.0 - 1.0) * amplitude;
+            // Reduce the amplitude for the following layer.
+            amplitude *= self.persistence;
+        }
+        result + 0.5
+    }
+
+    fn bounds(&self, layers: usize) -> (f64, f64) {
+        let sum = amplitude_sum(1.0, self.persistence.abs(), layers);
+        (0.5 - sum, 0.5 + sum)
+    }
+}
+
+impl_mp!(BillowBlender);
+
+/// A blender implementing Musgrave's basic multifractal, where each successive layer is scaled by
+/// the combined value of all the layers before it.
+///
+/// Areas where the lower layers are near zero stay smooth, while areas further from zero receive
+/// more detail.
+#[derive(Clone, Copy, Debug)]
+pub struct BasicMultiBlender {
+    /// Multiplier for the amplitude of each successive layer of noise.
+    pub persistence: f64,
+}
+
+impl BasicMultiBlender {
+    pub fn new(persistence: f64) -> Self {
+        Self { persistence }
+    }
+}
+
+impl Default for BasicMultiBlender {
+    fn default() -> Self {
+        Self {
+            persistence: DEFAULT_PERSISTENCE,
+        }
+    }
+}
+
+impl LayerBlender for BasicMultiBlender {
+    fn blend(&self, layer_values: &[f64]) -> f64 {
+        debug_assert!(!layer_values.is_empty());
+        // The first layer is unscaled.
+        let mut result = layer_values[0];
+        let mut amplitude = self.persistence;
+        for value in &layer_values[1..] {
+            // Scale the signal by the current 'altitude' of the function.
+            result += value * amplitude * result;
+            // Reduce the amplitude for the following layer.
+            amplitude *= self.persistence;
+        }
+        result
+    }
+
+    fn bounds(&self, layers: usize) -> (f64, f64) {
+        // Each layer can grow the magnitude of the output by at most a factor of
+        // 1 + persistence^i.
+        let mut bound = 1.0;
+        let mut amplitude = self.persistence.abs();
+        for _ in 1..layers {
+            bound *= 1.0 + amplitude;
+            amplitude *= self.persistence.abs();
+        }
+        (-bound, bound)
+    }
+}
+
+impl_mp!(BasicMultiBlender);
+
+/// A blender implementing Musgrave's hybrid multifractal.
+///
+/// Each layer is weighted by the product of the layers before it, clamped to at most 1.0. Valleys
+/// stay smooth while peaks become rough, which is a good fit for eroded terrain. `offset` is added
+/// to every layer value before weighting; larger offsets give rougher results. The default offset
+/// of 0.7 is the one Musgrave uses, which keeps the weights positive for most layer values.
+#[derive(Clone, Copy, Debug)]
+pub struct HybridMultiBlender {
+    /// Value added to each layer before it is weighted.
+    pub offset: f64,
+    /// Multiplier for the amplitude of each successive layer of noise.
+    pub persistence: f64,
+}
+
+impl HybridMultiBlender {
+    pub const DEFAULT_OFFSET: f64 = 0.7;
+
+    pub fn new(offset: f64, persistence: f64) -> Self {
+        Self {
+            offset,
+            persistence,
+        }
+    }
+}
+
+impl Default for HybridMultiBlender {
+    fn default() -> Self {
+        Self {
+            offset: Self::DEFAULT_OFFSET,
+            persistence: DEFAULT_PERSISTENCE,
+        }
+    }
+}
+
+impl LayerBlender for HybridMultiBlender {
+    fn blend(&self, layer_values: &[f64]) -> f64 {
+        debug_assert!(!layer_values.is_empty());
+        let mut result = layer_values[0] + self.offset;
+        let mut weight = result;
+        let mut amplitude = self.persistence;
+        for value in &layer_values[1..] {
+            // Prevent divergence.
+            weight = weight.min(1.0);
+            let signal = (value + self.offset) * amplitude;
+            // Add the weighted signal, then weight the following layer by this one.
+            result += weight * signal;
+            weight *= signal;
+            // Reduce the amplitude for the following layer.
+            amplitude *= self.persistence;
+        }
+        result
+    }
+
+    fn bounds(&self, layers: usize) -> (f64, f64) {
+        // Only the upper end of the weight is clamped, so track the largest magnitude the weight
+        // and each signal can reach.
+        let max_value = 1.0 + self.offset.abs();
+        let mut bound = max_value;
+        let mut weight = max_value;
+        let mut amplitude = self.persistence.abs();
+        for _ in 1..layers {
+            let signal = max_value * amplitude;
+            bound += weight * signal;
+            weight *= signal;
+            amplitude *= self.persistence.abs();
+        }
+        (-bound, bound)
+    }
+}
+
+impl_mp!(HybridMultiBlender);
+
+/// A blender implementing Musgrave's spectral fBm, where layer i has an amplitude of
+/// lacunarity<sup>-H·i</sup> for the Hurst exponent H.
+///
+/// The number of octaves may be fractional, in which case the last octave is faded in by the
+/// fractional part. Layers past the last octave are ignored. Changing the octave count
+/// continuously therefore changes the output continuously, which avoids popping when the level
+/// of detail changes.
+///
+/// `lacunarity` must match the lacunarity of the fractal this blender is used in.
+/// `Fractal::with_lacunarity` keeps the two in sync.
+#[derive(Clone, Copy, Debug)]
+pub struct SpectralBlender {
+    /// Hurst exponent, controlling how quickly the amplitude falls off with frequency. A value of
+    /// 1.0 gives the classic 1/f spectrum, lower values give rougher results.
+    pub hurst_exponent: f64,
+    /// Frequency multiplier between successive layers.
+    pub lacunarity: f64,
+    /// Number of octaves to blend, which may be fractional. Default is infinity, meaning every
+    /// layer is blended at full amplitude.
+    pub octaves: f64,
+}
+
+impl SpectralBlender {
+    pub const DEFAULT_HURST_EXPONENT: f64 = 1.0;
+
+    pub fn new(hurst_exponent: f64, lacunarity: f64, octaves: f64) -> Self {
+        Self {
+            hurst_exponent,
+            lacunarity,
+            octaves,
+        }
+    }
+
+    /// Returns the amplitude of every layer, taking a partial last octave into account.
+    fn amplitudes(&self, layers: usize) -> impl Iterator<Item = f64> {
+        let persistence = self.lacunarity.powf(-self.hurst_exponent);
+        let octaves = self.octaves;
+        (0..layers).scan(1.0, move |amplitude, i| {
+            let weight = (octaves - i as f64).clamp(0.0, 1.0);
+            let result = *amplitude * weight;
+            *amplitude *= persistence;
+            Some(result)
+        })
+    }
+}
+
+impl Default for SpectralBlender {
+    fn default() -> Self {
+        Self::new(
+            Self::DEFAULT_HURST_EXPONENT,
+            DEFAULT_LACUNARITY,
+            f64::INFINITY,
+        )
+    }
+}
+
+impl LayerBlender for SpectralBlender {
+    fn blend(&self, layer_values: &[f64]) -> f64 {
+        debug_assert!(!layer_values.is_empty());
+        layer_values
+            .iter()
+            .zip(self.amplitudes(layer_values.len()))
+            .map(|(value, amplitude)| value * amplitude)
+            .sum()
+    }
+
+    fn bounds(&self, layers: usize) -> (f64, f64) {
+        let bound: f64 = self.amplitudes(layers).sum();
+        (-bound, bound)
+    }
+
+    fn set_lacunarity(&mut self, lacunarity: f64) {
+        self.lacunarity = lacunarity;
+    }
+}
+
+/// A blender which multiplies each layer by an explicit weight.
+///
+/// Layer i is multiplied by `weights[i]`, and layers without a weight are ignored. This is useful
+/// for tuning the amplitude of each octave by hand.
+#[derive(Clone, Debug)]
+pub struct WeightedBlender {
+    /// Weight of each layer, starting with the first.
+    pub weights: Vec<f64>,
+}
+
+impl WeightedBlender {
+    pub fn new(weights: Vec<f64>) -> Self {
+        Self { weights }
+    }
+}
+
+impl Default for WeightedBlender {
+    /// Weights matching `HomogenousBlender` with the default persistence, for `MAX_LAYERS`
+    /// layers.
+    fn default() -> Self {
+        Self::new(
+            (0..MAX_LAYERS)
+                .map(|i| DEFAULT_PERSISTENCE.powi(i as i32))
+                .collect(),
+        )
+    }
+}
+
+impl LayerBlender for WeightedBlender {
+    fn blend(&self, layer_values: &[f64]) -> f64 {
+        debug_assert!(!layer_values.is_empty());
+        layer_values
+            .iter()
+            .zip(&self.weights)
+            .map(|(value, weight)| value * weight)
+            .sum()
+    }
+
+    fn bounds(&self, layers: usize) -> (f64, f64) {
+        let bound: f64 = self.weights.iter().take(layers).map(|w| w.abs()).sum();
+        (-bound, bound)
+    }
+}
+
+/// A blender which calls a closure to combine the layer values.
+///
+/// Since nothing is known about the closure, `bounds` reports the bounds given to `with_bounds`,
+/// which default to [-1, 1].
+#[derive(Clone, Copy, Debug)]
+pub struct FnBlender<Func> {
+    function: Func,
+    bounds: (f64, f64),
+}
+
+impl<Func> FnBlender<Func>
+where
+    Func: Fn(&[f64]) -> f64,
+{
+    pub fn new(function: Func) -> Self {
+        Self {
+            function,
+            bounds: (-1.0, 1.0),
+        }
+    }
+
+    /// Sets the lowest and highest values the closure can return, used by
+    /// `Fractal::normalized`.
+    pub fn with_bounds(self, lower_bound: f64, upper_bound: f64) -> Self {
+        Self {
+            bounds: (lower_bound, upper_bound),
+            ..self
+        }
+    }
+}
+
+impl<Func> LayerBlender for FnBlender<Func>
+where
+    Func: Fn(&[f64]) -> f64,
+{
+    fn blend(&self, layer_values: &[f64]) -> f64 {
+        (self.function)(layer_values)
+    }
+
+    fn bounds(&self, _layers: usize) -> (f64, f64) {
+        self.bounds
+    }
+}
 
 /// A noise function which is built up of multiple layers of a simpler noise function.
 ///
@@ -212,10 +684,20 @@ impl_ma!(RidgedBlender);
 /// A `LayerBlender` is used to combine the values from each layer into a final value. The simplest
 /// available is `HomogenousBlender`, which gives each layer a successively smaller amplitude.
 ///
+/// The first layer is sampled at `frequency`, which defaults to 1.0.
+///
 /// There are several type aliases which can be used to easily construct helpful fractal noise
-/// functions:
+/// functions: `Fbm`, `Billow`, `BasicMulti`, `HybridMulti`, `RidgedMulti` and `HeteroFractal`.
 /// ```rust
-/// let
+/// use noise::{fractals::RidgedMulti, NoiseFn, Seedable};
+///
+/// let ridged = RidgedMulti::default()
+///     .with_seed(7)
+///     .with_octaves(8)
+///     .with_frequency(2.0)
+///     .with_lacunarity(2.0)
+///     .with_persistence(0.5);
+/// let val = ridged.get([42.4, 37.7]);
 /// ```
 #[derive(Clone, Debug)]
 pub struct Fractal<
@@ -226,6 +708,8 @@ pub struct Fractal<
     layers: Vec<BaseFunction>,
     transform: Transform,
     blender: Blender,
+    frequency: f64,
+    normalized: bool,
     seed: u32,
 }
 
@@ -242,6 +726,25 @@ pub type FractalPerlin = Fractal;
 /// not be as damped and thus will grow more jagged as iteration progresses.
 pub type HeteroFractal = Fractal<HeterogenousBlender>;
 
+/// Fractal Brownian motion: layers of Perlin noise with successively higher frequencies and lower
+/// amplitudes. This is the same type as `FractalPerlin`.
+pub type Fbm = Fractal<HomogenousBlender>;
+
+/// Fractal noise made of folded Perlin noise layers, see `BillowBlender`.
+pub type Billow = Fractal<BillowBlender>;
+
+/// Musgrave's basic multifractal, see `BasicMultiBlender`.
+pub type BasicMulti = Fractal<BasicMultiBlender>;
+
+/// Musgrave's hybrid multifractal, see `HybridMultiBlender`.
+pub type HybridMulti = Fractal<HybridMultiBlender>;
+
+/// Musgrave's ridged multifractal, see `RidgedBlender`.
+pub type RidgedMulti = Fractal<RidgedBlender>;
+
+/// Musgrave's spectral fBm parameterized by the Hurst exponent, see `SpectralBlender`.
+pub type SpectralFbm = Fractal<SpectralBlender>;
+
 impl<B, F> Default for Fractal<B, F, UniformScale<f64>>
 where
     B: Default + LayerBlender,
@@ -266,23 +769,27 @@ where
     /// random.
     pub const DEFAULT_SEED: u32 = 0xD078_6B3E;
     pub const DEFAULT_LAYERS: u32 = 6;
-    pub const MAX_LAYERS: usize = 32;
+    pub const DEFAULT_FREQUENCY: f64 = 1.0;
+    pub const MAX_LAYERS: usize = MAX_LAYERS;
 
+    /// # Panics
+    /// Panics if `layers` is zero or greater than `MAX_LAYERS`.
     pub fn new(layers: u32, transform: T, blender: B) -> Self
     where
         F: Default,
     {
+        assert!(layers > 0 && layers as usize <= MAX_LAYERS);
         let seed = Self::DEFAULT_SEED;
-        // Using an rng to create the seeds ensures that similar seeds produce
-        // different results.
-        let mut seed_gen = rand_xorshift::XorShiftRng::seed_from_u64(seed as _);
-        let layers = (0..layers)
-            .map(|_| F::default().with_seed(seed_gen.gen()))
+        let layers = layer_seeds(seed)
+            .take(layers as usize)
+            .map(|seed| F::default().with_seed(seed))
             .collect();
         Self {
             layers,
             transform,
             blender,
+            frequency: Self::DEFAULT_FREQUENCY,
+            normalized: false,
             seed,
         }
     }
@@ -294,13 +801,15 @@ where
         NewF: Seedable + Clone,
     {
         debug_assert!(self.layers.len() > 0);
-        let mut seed_gen = rand_xorshift::XorShiftRng::seed_from_u64(self.seed as _);
-        let layers = (0..self.layers.len())
-            .map(|_| function_template.clone().with_seed(seed_gen.gen()))
+        let layers = layer_seeds(self.seed)
+            .take(self.layers.len())
+            .map(|seed| function_template.clone().with_seed(seed))
             .collect();
         Fractal {
             layers,
             blender: self.blender,
+            frequency: self.frequency,
+            normalized: self.normalized,
             seed: self.seed,
             transform: self.transform,
         }
@@ -315,38 +824,91 @@ where
         self.with_function(NewF::default())
     }
 
+    /// # Panics
+    /// Panics if `layers` is zero or greater than `MAX_LAYERS`.
     pub fn with_layers(self, layers: usize) -> Self
     where
         F: Clone,
     {
-        assert!(layers > 0);
-        let current_num_layers = self.layers.len();
-        let layers = if current_num_layers == layers {
-            self.layers
-        } else if current_num_layers > layers {
-            let mut o = self.layers;
-            drop(o.split_off(layers));
-            o
-        } else {
-            let mut o = self.layers;
-            debug_assert!(o.len() > 0);
-            let template = o.first().unwrap();
-            let mut seed_gen = rand_xorshift::XorShiftRng::seed_from_u64(self.seed as _);
-            // This ensures that the new layers will get the same seeds as if they were created
-            // all at once in new() or similar.
-            for _ in 0..current_num_layers {
-                seed_gen.gen::<u32>();
-            }
-            let mut next = Vec::new();
-            for _ in current_num_layers..layers {
-                next.push(template.clone().with_seed(seed_gen.gen()));
-            }
-            o.append(&mut next);
-            o
-        };
+        assert!(layers > 0 && layers <= MAX_LAYERS);
+        let layers = resize_layers(self.layers, layers, self.seed);
         Self { layers, ..self }
     }
 
+    /// Same as `with_layers`, for those used to calling each layer an octave.
+    pub fn with_octaves(self, octaves: usize) -> Self
+    where
+        F: Clone,
+    {
+        self.with_layers(octaves)
+    }
+
+    with!(pub frequency: f64);
+
+    /// The noise functions making up each layer, starting with the first.
+    pub fn layers(&self) -> &[F] {
+        &self.layers
+    }
+
+    /// The transform applied between successive layers.
+    pub fn transform(&self) -> &T {
+        &self.transform
+    }
+
+    /// The blender combining the values of all layers.
+    pub fn blender(&self) -> &B {
+        &self.blender
+    }
+
+    /// The frequency the first layer is sampled at.
+    pub fn frequency(&self) -> f64 {
+        self.frequency
+    }
+
+    /// Returns the value of each layer at `point`, before blending. Each layer is sampled at the
+    /// point it would be sampled at by `get`, starting with the first.
+    pub fn get_layers<'a, P>(&'a self, point: P) -> impl Iterator<Item = f64> + 'a
+    where
+        P: SamplePoint + Clone + 'a,
+        F: NoiseFn<P>,
+        T: PointTransform<P>,
+        UniformScale<f64>: PointTransform<P>,
+    {
+        let point = UniformScale::new(self.frequency).transform(point);
+        self.layers.iter().scan(point, move |point, layer| {
+            let value = layer.get(point.clone());
+            // Apply the transform for the next layer.
+            *point = self.transform.transform(point.clone());
+            Some(value)
+        })
+    }
+
+    /// Returns a noise function which outputs the value of a single layer, sampled where `get`
+    /// would sample it.
+    ///
+    /// # Panics
+    /// Panics if `index` is not less than the number of layers.
+    pub fn octave(&self, index: usize) -> FractalOctave<'_, B, F, T> {
+        assert!(index < self.layers.len());
+        FractalOctave {
+            fractal: self,
+            index,
+        }
+    }
+
+    /// Returns this fractal modified to map the theoretical output range reported by
+    /// `LayerBlender::bounds` onto [-1, 1].
+    ///
+    /// Because the bounds assume the worst case for every layer, the normalized output rarely
+    /// reaches -1 or 1, but it never leaves that range as long as each layer stays within [-1, 1].
+    /// Blenders which don't report finite bounds are left unnormalized.
+    pub fn normalized(self) -> Self {
+        Self {
+            normalized: true,
+            ..self
+        }
+    }
+
     /// Returns this fractal modified to use the provided point transformer repeatedly for each
     /// layer. For example, the first layer will have no transformation applied, while the fourth
     /// layer will have the transformation applied three times.
@@ -355,6 +917,8 @@ where
             transform,
             blender: self.blender,
             layers: self.layers,
+            frequency: self.frequency,
+            normalized: self.normalized,
             seed: self.seed,
         }
     }
@@ -365,20 +929,42 @@ where
         Fractal {
             blender,
             layers: self.layers,
+            frequency: self.frequency,
+            normalized: self.normalized,
             seed: self.seed,
             transform: self.transform,
         }
     }
 }
 
+impl<B, F, T> Fractal<B, F, T>
+where
+    F: Seedable,
+    B: LayerBlender + ModifiableAccumulation,
+{
+    /// Returns this fractal modified to accumulate layers the way older versions did, counting
+    /// the first layer twice. Only use this to keep the output of existing presets unchanged.
+    pub fn with_legacy_accumulation(self, legacy_accumulation: bool) -> Self {
+        let mut this = self;
+        this.blender.set_legacy_accumulation(legacy_accumulation);
+        this
+    }
+}
+
 impl<F, B, E> Fractal<B, F, UniformScale<E>>
 where
     F: Seedable,
     B: LayerBlender,
+    E: ToPrimitive,
 {
-    /// Returns this fractal modified to scale each layer by the provided amount.
+    /// Returns this fractal modified to scale each layer by the provided amount. The blender is
+    /// told about the new lacunarity, so blenders such as `SpectralBlender` stay in sync.
     pub fn with_lacunarity(self, lacunarity: E) -> Self {
-        self.with_transform(UniformScale::new(lacunarity))
+        let mut this = self;
+        if let Some(value) = lacunarity.to_f64() {
+            this.blender.set_lacunarity(value);
+        }
+        this.with_transform(UniformScale::new(lacunarity))
     }
 }
 
@@ -410,28 +996,225 @@ where
     }
 }
 
-impl<P, B, F, T> NoiseFn<P> for Fractal<B, F, T>
+impl<F, T> Fractal<SpectralBlender, F, T>
+where
+    F: Seedable,
+{
+    /// Returns this fractal modified so that layer i has an amplitude of
+    /// lacunarity<sup>-H·i</sup>.
+    pub fn with_hurst_exponent(self, hurst_exponent: f64) -> Self {
+        let mut this = self;
+        this.blender.hurst_exponent = hurst_exponent;
+        this
+    }
+
+    /// Returns this fractal modified to blend the given, possibly fractional, number of octaves.
+    /// The number of layers is rounded up to fit the last partial octave.
+    ///
+    /// # Panics
+    /// Panics if `octaves` is not positive or is greater than `MAX_LAYERS`.
+    pub fn with_fractional_octaves(self, octaves: f64) -> Self
+    where
+        F: Clone,
+    {
+        assert!(octaves > 0.0);
+        let mut this = self.with_layers(octaves.ceil() as usize);
+        this.blender.octaves = octaves;
+        this
+    }
+}
+
+/// A single layer of a `Fractal`, created by `Fractal::octave`.
+#[derive(Clone, Copy, Debug)]
+pub struct FractalOctave<'a, B: LayerBlender, F: Seedable, T> {
+    fractal: &'a Fractal<B, F, T>,
+    index: usize,
+}
+
+impl<'a, B: LayerBlender, F: Seedable, T> FractalOctave<'a, B, F, T> {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<'a, P, B, F, T> NoiseFn<P> for FractalOctave<'a, B, F, T>
 where
     P: SamplePoint + Clone,
+    B: LayerBlender,
     F: Seedable + NoiseFn<P>,
     T: PointTransform<P>,
-    B: LayerBlender,
+    UniformScale<f64>: PointTransform<P>,
 {
     fn get(&self, point: P) -> f64 {
-        let mut point = point;
-        let values: Vec<f64> = self
-            .layers
-            .iter()
-            .map(move |layer| {
-                // Get the value for this layer.
-                let v = layer.get(point.clone());
-                // Apply the transform for the next layer.
-                point = self.transform.transform(point.clone());
-                v
-            })
-            .collect();
-        debug_assert!(values.len() > 0);
-        self.blender.blend(&values[..])
+        let fractal = self.fractal;
+        let mut point = UniformScale::new(fractal.frequency).transform(point);
+        for _ in 0..self.index {
+            point = fractal.transform.transform(point);
+        }
+        fractal.layers[self.index].get(point)
+    }
+}
+
+/// Layers are evaluated in the output type `O`, so single-precision layers use their `f32`
+/// kernels. The layer values are then converted to `f64`, because `LayerBlender` works in double
+/// precision, and the blended result is converted back to `O`.
+impl<P, O, B, F, T> NoiseFn<P, O> for Fractal<B, F, T>
+where
+    P: SamplePoint + Clone,
+    O: Float,
+    F: Seedable + NoiseFn<P, O>,
+    T: PointTransform<P>,
+    B: LayerBlender,
+    UniformScale<f64>: PointTransform<P>,
+{
+    fn get(&self, point: P) -> O {
+        debug_assert!(!self.layers.is_empty());
+        let result = blend_layers(
+            &self.blender,
+            &self.transform,
+            self.frequency,
+            point,
+            self.layers.len(),
+            |i, point| self.layers[i].get(point).to_f64().unwrap(),
+        );
+        O::from(self.normalize(result, self.layers.len())).unwrap()
+    }
+}
+
+/// Samples `count` layers and blends their values. The first layer is sampled at `point` scaled
+/// by `frequency`, and `transform` is applied once more for each following layer. `sample`
+/// returns the value of the layer with the given index at the given point.
+pub(crate) fn blend_layers<P, B, T, S>(
+    blender: &B,
+    transform: &T,
+    frequency: f64,
+    point: P,
+    count: usize,
+    mut sample: S,
+) -> f64
+where
+    P: SamplePoint + Clone,
+    B: LayerBlender,
+    T: PointTransform<P>,
+    S: FnMut(usize, P) -> f64,
+    UniformScale<f64>: PointTransform<P>,
+{
+    let mut point = UniformScale::new(frequency).transform(point);
+    let mut values = [0.0; MAX_LAYERS];
+    for (i, value) in values[..count].iter_mut().enumerate() {
+        // Get the value for this layer.
+        *value = sample(i, point.clone());
+        // Apply the transform for the next layer.
+        point = transform.transform(point);
+    }
+    blender.blend(&values[..count])
+}
+
+impl<B, F, T> Fractal<B, F, T>
+where
+    F: Seedable,
+    B: LayerBlender,
+{
+    /// Maps the blended value onto [-1, 1] if this fractal is normalized and its blender reports
+    /// finite bounds.
+    fn normalize(&self, value: f64, layers: usize) -> f64 {
+        let (lower, upper) = self.blender.bounds(layers);
+        if self.normalized && lower.is_finite() && upper.is_finite() && lower < upper {
+            (value - lower) / (upper - lower) * 2.0 - 1.0
+        } else {
+            value
+        }
+    }
+}
+
+/// Returns the seeds given to the layers of a fractal with the provided seed, starting with the
+/// first. Using an rng to create the seeds ensures that similar seeds produce different results.
+pub(crate) fn layer_seeds(seed: u32) -> impl Iterator<Item = u32> {
+    let mut seed_gen = rand_xorshift::XorShiftRng::seed_from_u64(seed as _);
+    std::iter::repeat_with(move || seed_gen.gen())
+}
+
+/// Truncates or extends `layers` to `count` layers. New layers are copies of the first one, and
+/// get the same seeds as if all layers were created at once from `seed`.
+pub(crate) fn resize_layers<F>(mut layers: Vec<F>, count: usize, seed: u32) -> Vec<F>
+where
+    F: Seedable + Clone,
+{
+    let current = layers.len();
+    if count <= current {
+        layers.truncate(count);
+    } else {
+        debug_assert!(current > 0);
+        let template = layers[0].clone();
+        layers.extend(
+            layer_seeds(seed)
+                .skip(current)
+                .take(count - current)
+                .map(|seed| template.clone().with_seed(seed)),
+        );
+    }
+    layers
+}
+
+/// Returns how much of a layer to keep when `frequency` times the filter width is `footprint`.
+/// Layers are kept fully up to half the Nyquist limit, and fade out completely at the limit.
+#[inline]
+pub(crate) fn nyquist_fade(footprint: f64) -> f64 {
+    (2.0 - footprint * 4.0).clamp(0.0, 1.0)
+}
+
+/// Layer i has a frequency of frequency · lacunarity<sup>i</sup>. Layers up to half the Nyquist
+/// limit for `filter_width` are kept fully, and the first layer above that is faded out until the
+/// layer before it reaches half the limit, so the output changes smoothly as the filter width
+/// changes. With a lacunarity of 2, this layer fades out completely at the Nyquist limit. The
+/// remaining layers are not evaluated. Once the first layer passes half the limit, the whole
+/// output fades to 0.0 like a single filtered generator, and nothing is evaluated past the limit.
+///
+/// Since at most one layer is partially faded, the layers are blended twice at most: once
+/// without the faded layer and once with it.
+impl<P, B, F> FilteredNoiseFn<P> for Fractal<B, F, UniformScale<f64>>
+where
+    P: SamplePoint + Clone,
+    F: Seedable + NoiseFn<P>,
+    B: LayerBlender,
+    UniformScale<f64>: PointTransform<P>,
+{
+    fn get_filtered(&self, point: P, filter_width: f64) -> f64 {
+        let lacunarity = self.transform.scale.abs();
+        let mut footprint = self.frequency.abs() * filter_width;
+        let fade = nyquist_fade(footprint);
+        if fade <= 0.0 {
+            return 0.0;
+        }
+        let mut kept = 1;
+        let mut weight = 0.0;
+        while kept < self.layers.len() {
+            footprint *= lacunarity;
+            if footprint <= 0.25 {
+                kept += 1;
+                continue;
+            }
+            weight = if lacunarity > 1.0 {
+                ((lacunarity - footprint * 4.0) / (lacunarity - 1.0)).clamp(0.0, 1.0)
+            } else {
+                nyquist_fade(footprint)
+            };
+            break;
+        }
+
+        let count = kept + usize::from(weight > 0.0);
+        let mut values = [0.0; MAX_LAYERS];
+        for (value, layer) in values.iter_mut().zip(self.get_layers(point).take(count)) {
+            *value = layer;
+        }
+        let mut result = self.blender.blend(&values[..kept]);
+        if count > kept {
+            // A faded layer adds its weighted contribution to the blend of the layers before it.
+            // This works for any blender, because a weight of zero leaves the result unchanged
+            // and a weight of one gives the unfiltered result.
+            result += (self.blender.blend(&values[..count]) - result) * weight;
+        }
+        self.normalize(result, count) * fade
     }
 }
 
@@ -444,15 +1227,17 @@ where
     fn with_seed(self, seed: u32) -> Self {
         // I just don't like putting bare 'mut' in public function headers.
         let this = self;
-        let mut seed_gen = rand_xorshift::XorShiftRng::seed_from_u64(seed as _);
         let layers = this
             .layers
             .into_iter()
-            .map(|layer| layer.with_seed(seed_gen.gen()))
+            .zip(layer_seeds(seed))
+            .map(|(layer, seed)| layer.with_seed(seed))
             .collect();
         Self {
             layers,
             blender: this.blender,
+            frequency: this.frequency,
+            normalized: this.normalized,
             seed: this.seed,
             transform: this.transform,
         }
@@ -462,3 +1247,223 @@ where
         self.seed
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Points shared by the fractal tests, away from the integer lattice of the generators.
+    pub(crate) const TEST_POINTS: [[f64; 2]; 3] = [[0.3, -1.7], [12.25, 3.5], [-4.0, 8.125]];
+
+    /// Outputs the x coordinate of the point whatever its seed, so that the value of each layer
+    /// of a fractal is known.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub(crate) struct XCoordinate {
+        seed: u32,
+    }
+
+    impl NoiseFn<[f64; 2]> for XCoordinate {
+        fn get(&self, point: [f64; 2]) -> f64 {
+            point[0]
+        }
+    }
+
+    impl Seedable for XCoordinate {
+        fn with_seed(self, seed: u32) -> Self {
+            Self { seed }
+        }
+
+        fn seed(&self) -> u32 {
+            self.seed
+        }
+    }
+
+    #[test]
+    fn normalized_output_is_divided_by_bounds() {
+        let fbm = Fbm::default()
+            .with_octaves(3)
+            .with_lacunarity(2.0)
+            .with_function(XCoordinate::default());
+        assert_eq!(fbm.blender().bounds(3), (-1.75, 1.75));
+        // Each layer contributes 0.3, for a total of 0.9.
+        let value: f64 = fbm.normalized().get(TEST_POINTS[0]);
+        assert!((value - 0.9 / 1.75).abs() < 1e-12);
+    }
+
+    #[test]
+    fn normalized_output_stays_in_range() {
+        fn check<B: LayerBlender + Default + Clone>() {
+            let fractal = Fractal::<B>::default().with_octaves(8).normalized();
+            for x in -20..20 {
+                for y in -20..20 {
+                    let value: f64 = fractal.get([x as f64 * 0.37, y as f64 * 0.71]);
+                    assert!((-1.0..=1.0).contains(&value));
+                }
+            }
+        }
+        check::<HomogenousBlender>();
+        check::<HeterogenousBlender>();
+        check::<RidgedBlender>();
+        check::<BillowBlender>();
+        check::<BasicMultiBlender>();
+        check::<HybridMultiBlender>();
+    }
+
+    #[test]
+    fn fractional_octaves_fade_in_linearly() {
+        let spectral = SpectralFbm::default()
+            .with_hurst_exponent(0.8)
+            .with_lacunarity(2.0);
+        let three = spectral.clone().with_fractional_octaves(3.0);
+        let half = spectral.clone().with_fractional_octaves(3.5);
+        let four = spectral.with_fractional_octaves(4.0);
+        for point in TEST_POINTS {
+            let expected = (three.get(point) + four.get(point)) / 2.0;
+            let actual: f64 = half.get(point);
+            assert!((expected - actual).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn half_octave_has_half_amplitude() {
+        let spectral = SpectralFbm::default()
+            .with_lacunarity(2.0)
+            .with_function(XCoordinate::default())
+            .with_fractional_octaves(2.5);
+        // With H = 1 every layer contributes 0.3, and the last one only half of it.
+        let value: f64 = spectral.get(TEST_POINTS[0]);
+        assert!((value - 0.75).abs() < 1e-12);
+    }
+
+    #[test]
+    fn spectral_blender_follows_lacunarity() {
+        let spectral = SpectralFbm::default().with_lacunarity(4.0);
+        assert_eq!(spectral.blender().lacunarity, 4.0);
+        // With H = 1 the amplitudes are 4^-i.
+        assert_eq!(
+            spectral.blender().blend(&[1.0, 1.0, -1.0]),
+            1.0 + 0.25 - 0.0625
+        );
+    }
+
+    #[test]
+    fn weighted_blender_ignores_unweighted_layers() {
+        let blender = WeightedBlender::new(vec![1.0, -0.5]);
+        assert_eq!(blender.blend(&[0.5, 0.25, 1.0]), 0.375);
+        assert_eq!(blender.bounds(3), (-1.5, 1.5));
+    }
+
+    #[test]
+    fn filtering_removes_layers_above_nyquist() {
+        let fbm = Fbm::default().with_lacunarity(2.0).with_octaves(6);
+        let first_layer = fbm.clone().with_octaves(1);
+        for point in TEST_POINTS {
+            let unfiltered: f64 = fbm.get(point);
+            assert!((fbm.get_filtered(point, 1e-6) - unfiltered).abs() < 1e-12);
+            let coarse: f64 = first_layer.get(point);
+            assert!((fbm.get_filtered(point, 0.25) - coarse).abs() < 1e-12);
+            // The first layer fades out like a single generator.
+            assert!((fbm.get_filtered(point, 0.375) - 0.5 * coarse).abs() < 1e-12);
+            assert_eq!(fbm.get_filtered(point, 0.5), 0.0);
+        }
+    }
+
+    #[test]
+    fn filtering_fades_one_layer_at_a_time() {
+        let fbm = Fbm::default().with_lacunarity(2.0).with_octaves(6);
+        let point = [0.3, -1.7];
+        let layers: Vec<f64> = fbm.get_layers(point).collect();
+        let first = fbm.blender().blend(&layers[..1]);
+        let both = fbm.blender().blend(&layers[..2]);
+        // The second layer has a footprint of 0.375, halfway between 0.25 and the limit.
+        let filtered = fbm.get_filtered(point, 0.1875);
+        assert!((filtered - (first + 0.5 * (both - first))).abs() < 1e-12);
+
+        // With a lacunarity below 2, a layer fades out until the one before it starts to.
+        let fbm = fbm.with_lacunarity(1.5);
+        let layers: Vec<f64> = fbm.get_layers(point).collect();
+        let fourth_faded_out = fbm.get_filtered(point, 0.25 / 2.25);
+        assert!((fourth_faded_out - fbm.blender().blend(&layers[..3])).abs() < 1e-6);
+        let third_faded_out = fbm.get_filtered(point, 0.25 / 1.5);
+        assert!((third_faded_out - fbm.blender().blend(&layers[..2])).abs() < 1e-6);
+    }
+
+    #[test]
+    fn layers_blend_to_fractal_value() {
+        let fbm = Fbm::default().with_frequency(1.5);
+        for point in TEST_POINTS {
+            let layers: Vec<f64> = fbm.get_layers(point).collect();
+            let expected: f64 = fbm.get(point);
+            assert_eq!(fbm.blender().blend(&layers), expected);
+            assert_eq!(fbm.octave(3).get(point), layers[3]);
+        }
+    }
+
+    #[test]
+    fn layers_are_sampled_at_scaled_points() {
+        let fbm = Fbm::default()
+            .with_octaves(3)
+            .with_lacunarity(2.0)
+            .with_frequency(1.5)
+            .with_function(XCoordinate::default());
+        let layers: Vec<f64> = fbm.get_layers(TEST_POINTS[0]).collect();
+        assert_eq!(layers.len(), 3);
+        for (layer, expected) in layers.iter().zip([0.45, 0.9, 1.8]) {
+            assert!((layer - expected).abs() < 1e-12);
+        }
+        assert!((fbm.octave(2).get(TEST_POINTS[0]) - 1.8).abs() < 1e-12);
+    }
+
+    #[test]
+    fn hybrid_multi_uses_reference_offset() {
+        let blender = HybridMultiBlender::default();
+        assert_eq!(blender.offset, 0.7);
+        // result = 0.8, then + 0.8 * (1.0 * 0.5), then + 0.4 * (0.5 * 0.25).
+        assert!((blender.blend(&[0.1, 0.3, -0.2]) - 1.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ridged_bounds_allow_negative_persistence() {
+        let blender = RidgedBlender::new(DEFAULT_ATTENUATION, -0.5);
+        // Amplitudes 1, -0.5, 0.25 give signal sums from -0.5 to 1.25.
+        assert_eq!(blender.bounds(3), (-1.625, 0.5625));
+        // Ridge signals of 1.0, 0.5 and 0.0 sum to 1.0 - 0.25 + 0.0.
+        let value = blender.blend(&[0.0, 0.0, 1.0]);
+        assert_eq!(value, 0.75 * 1.25 - 1.0);
+    }
+
+    #[test]
+    fn blenders_without_bounds_are_not_normalized() {
+        struct Unbounded;
+        impl LayerBlender for Unbounded {
+            fn blend(&self, layer_values: &[f64]) -> f64 {
+                layer_values.iter().sum()
+            }
+        }
+        let fractal = Fractal::<Unbounded>::new(2, UniformScale::new(2.0), Unbounded);
+        let point = [0.3, -1.7];
+        let expected: f64 = fractal.get(point);
+        let actual: f64 = fractal.normalized().get(point);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn legacy_accumulation_counts_first_layer_twice() {
+        let values = [0.5, -0.25, 0.75];
+        let mut blender = HomogenousBlender::new(0.5);
+        assert_eq!(blender.blend(&values), 0.5 - 0.125 + 0.1875);
+        blender.set_legacy_accumulation(true);
+        assert_eq!(blender.blend(&values), 0.5 + 0.25 - 0.0625 + 0.09375);
+    }
+
+    #[test]
+    fn frequency_scales_first_layer() {
+        let fbm = Fbm::default().with_octaves(3);
+        let scaled = fbm.clone().with_frequency(4.0);
+        for point in TEST_POINTS {
+            let expected: f64 = fbm.get([point[0] * 4.0, point[1] * 4.0]);
+            let actual: f64 = scaled.get(point);
+            assert!((expected - actual).abs() < 1e-12);
+        }
+    }
+}
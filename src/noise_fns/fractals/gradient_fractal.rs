@@ -0,0 +1,325 @@
+use crate::{
+    fractals::{
+        layer_seeds, resize_layers, Fractal, DEFAULT_LACUNARITY, DEFAULT_PERSISTENCE, MAX_LAYERS,
+    },
+    generators::Perlin,
+    transforms::{PointTransform, UniformScale},
+    GradientNoiseFn, NoiseFn, Seedable,
+};
+use num_traits::ToPrimitive;
+
+/// Structs implementing this trait combine the layers of a `GradientFractal`, using the gradient
+/// of each layer as well as its value.
+///
+/// Unlike a `LayerBlender`, the blender drives the evaluation, so it can choose where each layer
+/// is sampled based on the layers before it. A `LayerBlender` only receives the finished values of
+/// all layers, so blenders which displace later layers or damp them by slope, such as
+/// `SwissBlender` and `JordanBlender`, can't be expressed as one and only work with
+/// `GradientFractal`.
+pub trait GradientLayerBlender {
+    /// Blends `layers` layers. Each call to `sample` evaluates the next layer, starting with the
+    /// first, at that layer's point displaced by the given offset. It returns the value and the
+    /// gradient of the layer. `sample` must be called exactly `layers` times.
+    fn blend<S, const N: usize>(&self, layers: usize, sample: S) -> f64
+    where
+        S: FnMut([f64; N]) -> (f64, [f64; N]);
+
+    /// Called when the lacunarity of the fractal this blender is used in changes, like
+    /// `LayerBlender::set_lacunarity`. The default implementation does nothing.
+    fn set_lacunarity(&mut self, _lacunarity: f64) {}
+
+    /// Called when the frequency of the first layer of the fractal this blender is used in
+    /// changes. The default implementation does nothing.
+    fn set_frequency(&mut self, _frequency: f64) {}
+}
+
+/// A noise function built up of multiple layers, which are blended using their gradients.
+///
+/// Layers are created, seeded and transformed exactly like in `Fractal`, but they must be able to
+/// compute their own gradient by implementing `GradientNoiseFn`, as `Perlin` does for 2 and 3
+/// dimensions. A `GradientLayerBlender` combines them into the final value. Gradients are
+/// measured in the coordinates of each layer, before the transform back to the input point.
+///
+/// See `ErosionFractal`, `SwissTurbulence` and `JordanTurbulence`.
+#[derive(Clone, Debug)]
+pub struct GradientFractal<B, F: Seedable = Perlin, T = UniformScale<f64>> {
+    layers: Vec<F>,
+    transform: T,
+    blender: B,
+    frequency: f64,
+    seed: u32,
+}
+
+impl<B, F> Default for GradientFractal<B, F, UniformScale<f64>>
+where
+    B: GradientLayerBlender + Default,
+    F: Seedable + Default,
+{
+    /// Uses the same defaults as `Fractal`.
+    fn default() -> Self {
+        let seed = <Fractal>::DEFAULT_SEED;
+        let layers = layer_seeds(seed)
+            .take(<Fractal>::DEFAULT_LAYERS as usize)
+            .map(|seed| F::default().with_seed(seed))
+            .collect();
+        let mut blender = B::default();
+        blender.set_lacunarity(DEFAULT_LACUNARITY);
+        blender.set_frequency(<Fractal>::DEFAULT_FREQUENCY);
+        Self {
+            layers,
+            transform: UniformScale::new(DEFAULT_LACUNARITY),
+            blender,
+            frequency: <Fractal>::DEFAULT_FREQUENCY,
+            seed,
+        }
+    }
+}
+
+impl<B, F, T> GradientFractal<B, F, T>
+where
+    B: GradientLayerBlender,
+    F: Seedable,
+{
+    /// Returns this fractal noise function but modified to use the specified noise function
+    /// duplicated for each layer with different seeds assigned to each function.
+    pub fn with_function<NewF>(self, function_template: NewF) -> GradientFractal<B, NewF, T>
+    where
+        NewF: Seedable + Clone,
+    {
+        let layers = layer_seeds(self.seed)
+            .take(self.layers.len())
+            .map(|seed| function_template.clone().with_seed(seed))
+            .collect();
+        GradientFractal {
+            layers,
+            transform: self.transform,
+            blender: self.blender,
+            frequency: self.frequency,
+            seed: self.seed,
+        }
+    }
+
+    /// # Panics
+    /// Panics if `octaves` is zero or greater than `MAX_LAYERS`.
+    pub fn with_octaves(self, octaves: usize) -> Self
+    where
+        F: Clone,
+    {
+        assert!(octaves > 0 && octaves <= MAX_LAYERS);
+        Self {
+            layers: resize_layers(self.layers, octaves, self.seed),
+            ..self
+        }
+    }
+
+    /// Returns this fractal modified so that the first layer is sampled at the given frequency.
+    /// The blender is told about the new frequency, like with `with_lacunarity`.
+    pub fn with_frequency(mut self, frequency: f64) -> Self {
+        self.blender.set_frequency(frequency);
+        Self { frequency, ..self }
+    }
+
+    /// Returns this fractal modified to use the provided point transformer repeatedly for each
+    /// layer.
+    pub fn with_transform<NewT>(self, transform: NewT) -> GradientFractal<B, F, NewT> {
+        GradientFractal {
+            layers: self.layers,
+            transform,
+            blender: self.blender,
+            frequency: self.frequency,
+            seed: self.seed,
+        }
+    }
+
+    /// Returns this fractal modified to use the provided blender to combine the layers. The
+    /// blender is told about the frequency of the first layer, but not about the lacunarity.
+    pub fn with_layer_blender<NewB>(self, mut blender: NewB) -> GradientFractal<NewB, F, T>
+    where
+        NewB: GradientLayerBlender,
+    {
+        blender.set_frequency(self.frequency);
+        GradientFractal {
+            layers: self.layers,
+            transform: self.transform,
+            blender,
+            frequency: self.frequency,
+            seed: self.seed,
+        }
+    }
+
+    /// The noise functions making up each layer, starting with the first.
+    pub fn layers(&self) -> &[F] {
+        &self.layers
+    }
+
+    /// The transform applied between successive layers.
+    pub fn transform(&self) -> &T {
+        &self.transform
+    }
+
+    /// The blender driving the evaluation of the layers.
+    pub fn blender(&self) -> &B {
+        &self.blender
+    }
+
+    /// The frequency the first layer is sampled at.
+    pub fn frequency(&self) -> f64 {
+        self.frequency
+    }
+}
+
+impl<B, F, E> GradientFractal<B, F, UniformScale<E>>
+where
+    B: GradientLayerBlender,
+    F: Seedable,
+    E: ToPrimitive,
+{
+    /// Returns this fractal modified to scale each layer by the provided amount. The blender is
+    /// told about the new lacunarity, so blenders such as `SwissBlender` stay in sync.
+    pub fn with_lacunarity(mut self, lacunarity: E) -> Self {
+        if let Some(value) = lacunarity.to_f64() {
+            self.blender.set_lacunarity(value);
+        }
+        self.with_transform(UniformScale::new(lacunarity))
+    }
+}
+
+impl<B, F, T, const N: usize> NoiseFn<[f64; N]> for GradientFractal<B, F, T>
+where
+    B: GradientLayerBlender,
+    F: Seedable + GradientNoiseFn<[f64; N]>,
+    T: PointTransform<[f64; N]>,
+{
+    fn get(&self, point: [f64; N]) -> f64 {
+        let mut point = UniformScale::new(self.frequency).transform(point);
+        let mut layers = self.layers.iter();
+        self.blender.blend(self.layers.len(), |offset: [f64; N]| {
+            let layer = layers.next().expect("sampled more layers than there are");
+            let mut displaced = point;
+            for (component, offset) in displaced.iter_mut().zip(&offset) {
+                *component += offset;
+            }
+            // Apply the transform for the next layer.
+            point = self.transform.transform(point);
+            layer.get_with_gradient(displaced)
+        })
+    }
+}
+
+impl<B, F, T> Seedable for GradientFractal<B, F, T>
+where
+    F: Seedable,
+{
+    /// Changes the seeds of all layers based on the provided seed.
+    fn with_seed(self, seed: u32) -> Self {
+        let layers = self
+            .layers
+            .into_iter()
+            .zip(layer_seeds(seed))
+            .map(|(layer, seed)| layer.with_seed(seed))
+            .collect();
+        Self {
+            layers,
+            seed,
+            ..self
+        }
+    }
+
+    fn seed(&self) -> u32 {
+        self.seed
+    }
+}
+
+/// A blender where each layer is damped by the slope of the layers before it, giving an eroded
+/// look.
+///
+/// The gradients of all layers sampled so far are summed, and layer i is added with an amplitude
+/// of persistence<sup>i</sup> / (1 + |∑∇|<sup>2</sup>). Steep slopes therefore keep little of the
+/// higher frequency detail while valleys and plateaus stay smooth, which resembles terrain shaped
+/// by erosion. This is the technique popularized by Inigo Quilez.
+///
+/// The damping only ever reduces the amplitude of a layer, so the output is within the same
+/// bounds as `Fbm` with the same persistence.
+#[derive(Clone, Copy, Debug)]
+pub struct ErosionBlender {
+    /// Multiplier for the amplitude of each successive layer of noise, before damping.
+    pub persistence: f64,
+}
+
+impl ErosionBlender {
+    pub fn new(persistence: f64) -> Self {
+        Self { persistence }
+    }
+}
+
+impl Default for ErosionBlender {
+    fn default() -> Self {
+        Self::new(DEFAULT_PERSISTENCE)
+    }
+}
+
+impl GradientLayerBlender for ErosionBlender {
+    fn blend<S, const N: usize>(&self, layers: usize, mut sample: S) -> f64
+    where
+        S: FnMut([f64; N]) -> (f64, [f64; N]),
+    {
+        let mut gradient_sum = [0.0; N];
+        let mut result = 0.0;
+        let mut amplitude = 1.0;
+        for _ in 0..layers {
+            let (value, gradient) = sample([0.0; N]);
+            for (sum, component) in gradient_sum.iter_mut().zip(&gradient) {
+                *sum += component;
+            }
+            // Damp this layer by the slope of all the layers so far.
+            let slope_squared: f64 = gradient_sum.iter().map(|g| g * g).sum();
+            result += value * amplitude / (1.0 + slope_squared);
+            // Reduce the amplitude for the following layer.
+            amplitude *= self.persistence;
+        }
+        result
+    }
+}
+
+/// Fractal noise where each layer is damped by the slope of the layers before it, see
+/// `ErosionBlender`.
+pub type ErosionFractal<F = Perlin, T = UniformScale<f64>> = GradientFractal<ErosionBlender, F, T>;
+
+impl<F, T> ErosionFractal<F, T>
+where
+    F: Seedable,
+{
+    /// Returns this fractal modified so that the amplitude of each successive layer is reduced
+    /// by the given amount, before damping.
+    pub fn with_persistence(mut self, persistence: f64) -> Self {
+        self.blender.persistence = persistence;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fractals::tests::TEST_POINTS;
+
+    #[test]
+    fn first_layer_is_damped_by_its_own_slope() {
+        let erosion = ErosionFractal::<Perlin>::default()
+            .with_octaves(1)
+            .with_frequency(2.0);
+        for point in TEST_POINTS {
+            let (value, [dx, dy]) =
+                erosion.layers()[0].get_with_gradient([point[0] * 2.0, point[1] * 2.0]);
+            let expected = value / (1.0 + dx * dx + dy * dy);
+            assert!((erosion.get(point) - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn later_layers_are_damped_by_the_summed_slope() {
+        let blender = ErosionBlender::default();
+        let result = blender.blend(2, |_: [f64; 2]| (0.5, [1.0, 0.0]));
+        // 0.5 / (1 + 1) for the first layer, and 0.5 * 0.5 / (1 + 4) for the second.
+        assert!((result - 0.3).abs() < 1e-12);
+    }
+}
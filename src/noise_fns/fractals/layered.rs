@@ -0,0 +1,341 @@
+use crate::{
+    fractals::{
+        blend_layers, layer_seeds, HomogenousBlender, LayerBlender, ModifiablePersistence,
+        DEFAULT_LACUNARITY, MAX_LAYERS,
+    },
+    transforms::{PointTransform, UniformScale},
+    NoiseFn, SamplePoint, Seedable,
+};
+use num_traits::ToPrimitive;
+use std::fmt;
+
+/// The boxed noise function of a `FractalLayer`, which can still be cloned and reseeded.
+trait LayerFunction<P> {
+    fn sample(&self, point: P) -> f64;
+
+    fn boxed_clone(&self) -> Box<dyn LayerFunction<P>>;
+
+    fn reseeded(&self, seed: u32) -> Box<dyn LayerFunction<P>>;
+}
+
+impl<P, F> LayerFunction<P> for F
+where
+    P: SamplePoint,
+    F: NoiseFn<P> + Seedable + Clone + 'static,
+{
+    fn sample(&self, point: P) -> f64 {
+        self.get(point)
+    }
+
+    fn boxed_clone(&self) -> Box<dyn LayerFunction<P>> {
+        Box::new(self.clone())
+    }
+
+    fn reseeded(&self, seed: u32) -> Box<dyn LayerFunction<P>> {
+        Box::new(self.clone().with_seed(seed))
+    }
+}
+
+/// A single layer of a `LayeredFractal`.
+///
+/// The noise function is boxed, so layers of different types can be stacked and chosen at
+/// runtime.
+pub struct FractalLayer<P> {
+    function: Box<dyn LayerFunction<P>>,
+    seed: u32,
+
+    /// Multiplier applied to the value of this layer before it is blended. This does not replace
+    /// the falloff applied by the blender: with the persistence of `HomogenousBlender`, layer i
+    /// ends up with an amplitude of weight · persistence<sup>i</sup>. Default is 1.0.
+    pub weight: f64,
+}
+
+impl<P: SamplePoint> FractalLayer<P> {
+    /// Creates a layer from `function`, after giving it the provided seed.
+    pub fn new<F>(function: F, seed: u32) -> Self
+    where
+        F: NoiseFn<P> + Seedable + Clone + 'static,
+    {
+        Self {
+            function: Box::new(function.with_seed(seed)),
+            seed,
+            weight: 1.0,
+        }
+    }
+
+    pub fn with_weight(self, weight: f64) -> Self {
+        Self { weight, ..self }
+    }
+}
+
+impl<P> Clone for FractalLayer<P> {
+    fn clone(&self) -> Self {
+        Self {
+            function: self.function.boxed_clone(),
+            seed: self.seed,
+            weight: self.weight,
+        }
+    }
+}
+
+impl<P> fmt::Debug for FractalLayer<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FractalLayer")
+            .field("seed", &self.seed)
+            .field("weight", &self.weight)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<P> Seedable for FractalLayer<P> {
+    /// Gives the noise function of this layer the provided seed.
+    fn with_seed(self, seed: u32) -> Self {
+        Self {
+            function: self.function.reseeded(seed),
+            seed,
+            ..self
+        }
+    }
+
+    fn seed(&self) -> u32 {
+        self.seed
+    }
+}
+
+/// A fractal whose layers can each be a different noise function.
+///
+/// Where `Fractal` clones one noise function for every layer, this holds a list of boxed
+/// `FractalLayer`s, each with its own seed and weight. For example, the first two
+/// layers could be `Worley` noise to create the large shapes, and the remaining layers `Perlin`
+/// noise to add detail. Other than that, layers are transformed and blended exactly like in
+/// `Fractal`.
+///
+/// ```rust
+/// use noise::{
+///     fractals::{FractalLayer, LayeredFractal},
+///     generators::{Perlin, Worley},
+///     NoiseFn,
+/// };
+///
+/// let fractal = LayeredFractal::default()
+///     .add_layer(FractalLayer::new(Worley::default(), 1))
+///     .add_layer(FractalLayer::new(Worley::default(), 2).with_weight(0.5))
+///     .add_layer(FractalLayer::new(Perlin::default(), 3))
+///     .add_layer(FractalLayer::new(Perlin::default(), 4));
+/// let val = fractal.get([42.4, 37.7]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct LayeredFractal<P, B: LayerBlender = HomogenousBlender, T = UniformScale<f64>> {
+    layers: Vec<FractalLayer<P>>,
+    transform: T,
+    blender: B,
+    frequency: f64,
+    seed: u32,
+}
+
+impl<P> Default for LayeredFractal<P> {
+    fn default() -> Self {
+        Self::new(
+            UniformScale::new(DEFAULT_LACUNARITY),
+            HomogenousBlender::default(),
+        )
+    }
+}
+
+impl<P, B, T> LayeredFractal<P, B, T>
+where
+    B: LayerBlender,
+{
+    pub const DEFAULT_FREQUENCY: f64 = 1.0;
+
+    /// Creates a fractal without any layers. Until a layer is added, the fractal outputs 0.0
+    /// everywhere.
+    pub fn new(transform: T, blender: B) -> Self {
+        Self {
+            layers: Vec::new(),
+            transform,
+            blender,
+            frequency: Self::DEFAULT_FREQUENCY,
+            seed: 0,
+        }
+    }
+
+    /// Adds a layer on top of the existing ones. It will be sampled at a higher frequency than
+    /// all the layers before it.
+    ///
+    /// # Panics
+    /// Panics if the fractal already has `MAX_LAYERS` layers.
+    pub fn add_layer(mut self, layer: FractalLayer<P>) -> Self {
+        assert!(self.layers.len() < MAX_LAYERS);
+        self.layers.push(layer);
+        self
+    }
+
+    pub fn layers(&self) -> &[FractalLayer<P>] {
+        &self.layers
+    }
+
+    with!(pub frequency: f64);
+
+    /// Returns this fractal modified to use the provided point transformer repeatedly for each
+    /// layer.
+    pub fn with_transform<NewT>(self, transform: NewT) -> LayeredFractal<P, B, NewT> {
+        LayeredFractal {
+            layers: self.layers,
+            transform,
+            blender: self.blender,
+            frequency: self.frequency,
+            seed: self.seed,
+        }
+    }
+
+    /// Returns this fractal modified to use the provided layer blender to combine the values
+    /// produced by all noise layers.
+    pub fn with_layer_blender<NewB: LayerBlender>(
+        self,
+        blender: NewB,
+    ) -> LayeredFractal<P, NewB, T> {
+        LayeredFractal {
+            layers: self.layers,
+            transform: self.transform,
+            blender,
+            frequency: self.frequency,
+            seed: self.seed,
+        }
+    }
+}
+
+impl<P, B, E> LayeredFractal<P, B, UniformScale<E>>
+where
+    B: LayerBlender,
+    E: ToPrimitive,
+{
+    /// Returns this fractal modified to scale each layer by the provided amount. The blender is
+    /// told about the new lacunarity, like in `Fractal::with_lacunarity`.
+    pub fn with_lacunarity(mut self, lacunarity: E) -> Self {
+        if let Some(value) = lacunarity.to_f64() {
+            self.blender.set_lacunarity(value);
+        }
+        self.with_transform(UniformScale::new(lacunarity))
+    }
+}
+
+impl<P, B, T> LayeredFractal<P, B, T>
+where
+    B: LayerBlender + ModifiablePersistence,
+{
+    /// Returns this fractal modified so that the amplitude of each successive layer is reduced
+    /// by the given amount.
+    pub fn with_persistence(mut self, persistence: f64) -> Self {
+        self.blender.set_persistence(persistence);
+        self
+    }
+}
+
+impl<P, B, T> NoiseFn<P> for LayeredFractal<P, B, T>
+where
+    P: SamplePoint + Clone,
+    T: PointTransform<P>,
+    B: LayerBlender,
+    UniformScale<f64>: PointTransform<P>,
+{
+    fn get(&self, point: P) -> f64 {
+        if self.layers.is_empty() {
+            return 0.0;
+        }
+        blend_layers(
+            &self.blender,
+            &self.transform,
+            self.frequency,
+            point,
+            self.layers.len(),
+            |i, point| {
+                let layer = &self.layers[i];
+                layer.function.sample(point) * layer.weight
+            },
+        )
+    }
+}
+
+impl<P, B, T> Seedable for LayeredFractal<P, B, T>
+where
+    B: LayerBlender,
+{
+    /// Changes the seeds of all layers based on the provided seed, like `Fractal::with_seed`.
+    fn with_seed(self, seed: u32) -> Self {
+        let layers = self
+            .layers
+            .into_iter()
+            .zip(layer_seeds(seed))
+            .map(|(layer, seed)| layer.with_seed(seed))
+            .collect();
+        Self {
+            layers,
+            seed,
+            ..self
+        }
+    }
+
+    fn seed(&self) -> u32 {
+        self.seed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fractals::tests::{XCoordinate, TEST_POINTS},
+        generators::{Perlin, Worley},
+    };
+
+    #[test]
+    fn layers_keep_their_own_type_and_seed() {
+        let fractal = LayeredFractal::default()
+            .with_lacunarity(2.0)
+            .with_persistence(0.5)
+            .add_layer(FractalLayer::new(Worley::default(), 5))
+            .add_layer(FractalLayer::new(Perlin::default(), 6).with_weight(3.0));
+        let worley = Worley::new(5);
+        let perlin = Perlin::new(6);
+        for [x, y] in TEST_POINTS {
+            let expected = worley.get([x, y]) + 0.5 * 3.0 * perlin.get([x * 2.0, y * 2.0]);
+            assert!((fractal.get([x, y]) - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn layer_weight_scales_blended_value() {
+        let fractal = LayeredFractal::default()
+            .with_lacunarity(2.0)
+            .add_layer(FractalLayer::new(XCoordinate::default(), 1))
+            .add_layer(FractalLayer::new(XCoordinate::default(), 2).with_weight(3.0));
+        // 0.3 from the first layer, and 0.6 * 3.0 * 0.5 from the second.
+        assert!((fractal.get(TEST_POINTS[0]) - 1.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn empty_fractal_outputs_zero() {
+        let fractal = LayeredFractal::<[f64; 2]>::default();
+        assert_eq!(fractal.get(TEST_POINTS[0]), 0.0);
+    }
+
+    #[test]
+    fn reseeding_changes_every_layer() {
+        let fractal = LayeredFractal::default()
+            .add_layer(FractalLayer::new(Perlin::default(), 1))
+            .add_layer(FractalLayer::new(Worley::default(), 2));
+        let copy = fractal.clone();
+        let reseeded = fractal.with_seed(7);
+        assert_eq!(reseeded.seed(), 7);
+        assert!(reseeded
+            .layers()
+            .iter()
+            .zip(copy.layers())
+            .all(|(a, b)| a.seed() != b.seed()));
+        assert_ne!(reseeded.get(TEST_POINTS[0]), copy.get(TEST_POINTS[0]));
+        // Reseeding is deterministic.
+        let again = copy.clone().with_seed(7);
+        assert_eq!(reseeded.get(TEST_POINTS[0]), again.get(TEST_POINTS[0]));
+    }
+}
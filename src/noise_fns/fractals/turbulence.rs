@@ -0,0 +1,223 @@
+use crate::{
+    fractals::{GradientFractal, GradientLayerBlender, DEFAULT_LACUNARITY},
+    generators::Perlin,
+    transforms::UniformScale,
+};
+
+/// Adds `scale` times `vector` to `sum`.
+#[inline(always)]
+fn add_scaled<const N: usize>(sum: &mut [f64; N], vector: [f64; N], scale: f64) {
+    for (sum, component) in sum.iter_mut().zip(&vector) {
+        *sum += component * scale;
+    }
+}
+
+/// A blender implementing Giliam de Carpentier's Swiss turbulence, from "Procedural terrain" in
+/// GPU Pro 3.
+///
+/// Each layer is folded into ridges like `RidgedBlender`, and the amplitude of the following
+/// layer is scaled by the sum so far, so that detail builds up on the ridges. Each layer is also
+/// sampled at a point displaced along the accumulated gradient of the layers before it, which
+/// bends the ridges like flowing water would. Like the reference, the displacement is applied to
+/// the point before it is scaled for each layer, so layer i is sampled at
+/// (p + warp · ∑∇) · frequency · lacunarity<sup>i</sup>.
+///
+/// This blender needs the gradient of every layer and moves where later layers are sampled, so it
+/// only works with `GradientFractal`, see `SwissTurbulence`. `lacunarity` and `frequency` must
+/// match those of that fractal; `GradientFractal::with_lacunarity` and
+/// `GradientFractal::with_frequency` keep them in sync.
+#[derive(Clone, Copy, Debug)]
+pub struct SwissBlender {
+    /// Multiplier for the amplitude of each successive layer of noise.
+    pub gain: f64,
+    /// How far each layer is displaced along the accumulated gradient.
+    pub warp: f64,
+    /// Frequency multiplier between successive layers, used to scale the displacement.
+    pub lacunarity: f64,
+    /// Frequency of the first layer, used to scale the displacement.
+    pub frequency: f64,
+}
+
+impl SwissBlender {
+    pub const DEFAULT_GAIN: f64 = 0.5;
+    pub const DEFAULT_WARP: f64 = 0.15;
+
+    pub fn new(gain: f64, warp: f64) -> Self {
+        Self {
+            gain,
+            warp,
+            lacunarity: DEFAULT_LACUNARITY,
+            frequency: 1.0,
+        }
+    }
+}
+
+impl Default for SwissBlender {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_GAIN, Self::DEFAULT_WARP)
+    }
+}
+
+impl GradientLayerBlender for SwissBlender {
+    fn blend<S, const N: usize>(&self, layers: usize, mut sample: S) -> f64
+    where
+        S: FnMut([f64; N]) -> (f64, [f64; N]),
+    {
+        let mut result = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = self.frequency;
+        let mut gradient_sum = [0.0; N];
+        for _ in 0..layers {
+            // Displace the unscaled point, which moves this layer's point by the scaled offset.
+            let mut offset = [0.0; N];
+            add_scaled(&mut offset, gradient_sum, self.warp * frequency);
+            let (value, gradient) = sample(offset);
+            // Make the ridges.
+            result += amplitude * (1.0 - value.abs());
+            add_scaled(&mut gradient_sum, gradient, -amplitude * value);
+            // Grow more detail where the result is already high.
+            amplitude *= self.gain * result.clamp(0.0, 1.0);
+            frequency *= self.lacunarity;
+        }
+        result
+    }
+
+    fn set_lacunarity(&mut self, lacunarity: f64) {
+        self.lacunarity = lacunarity;
+    }
+
+    fn set_frequency(&mut self, frequency: f64) {
+        self.frequency = frequency;
+    }
+}
+
+/// A blender implementing Giliam de Carpentier's Jordan turbulence, from "Procedural terrain" in
+/// GPU Pro 3.
+///
+/// Each layer is squared, which creates rounded hills with sharp creases. The accumulated gradient
+/// of the layers before is used twice: to displace where each layer is sampled, and to damp
+/// the amplitude of layers on steep slopes. The first layer has its own gain, warp and damping,
+/// since it sets the overall shape of the terrain. As in the reference, the displacement is
+/// applied in the coordinates of each layer.
+///
+/// The first layer has an amplitude of 1.0 and the second of `first_gain · gain`, neither of them
+/// damped. Each following layer is multiplied by `gain` again and damped by the slope so far.
+///
+/// This blender needs the gradient of every layer and moves where later layers are sampled, so it
+/// only works with `GradientFractal`, see `JordanTurbulence`.
+#[derive(Clone, Copy, Debug)]
+pub struct JordanBlender {
+    /// Amplitude of the layers after the first, before `gain` is applied to them.
+    pub first_gain: f64,
+    /// Multiplier for the amplitude of each layer after the first.
+    pub gain: f64,
+    /// How far later layers are displaced along the gradient of the first layer.
+    pub first_warp: f64,
+    /// How far later layers are displaced along the gradient of the layers after the first.
+    pub warp: f64,
+    /// How much the gradient of the first layer damps later layers.
+    pub first_damp: f64,
+    /// How much the gradient of the layers after the first damps later layers.
+    pub damp: f64,
+    /// Overall strength of the damping, between 0.0 for none and 1.0 for full.
+    pub damp_scale: f64,
+}
+
+impl Default for JordanBlender {
+    fn default() -> Self {
+        Self {
+            first_gain: 0.8,
+            gain: 0.5,
+            first_warp: 0.4,
+            warp: 0.35,
+            first_damp: 1.0,
+            damp: 0.8,
+            damp_scale: 1.0,
+        }
+    }
+}
+
+impl GradientLayerBlender for JordanBlender {
+    fn blend<S, const N: usize>(&self, layers: usize, mut sample: S) -> f64
+    where
+        S: FnMut([f64; N]) -> (f64, [f64; N]),
+    {
+        debug_assert!(layers > 0);
+        // The first layer is sampled without any displacement and at full amplitude.
+        let (value, gradient) = sample([0.0; N]);
+        let mut result = value * value;
+        let mut warp_sum = [0.0; N];
+        let mut damp_sum = [0.0; N];
+        add_scaled(&mut warp_sum, gradient, self.first_warp * value);
+        add_scaled(&mut damp_sum, gradient, self.first_damp * value);
+
+        let mut amplitude = self.first_gain;
+        let mut damped_amplitude = amplitude * self.gain;
+        for _ in 1..layers {
+            let (value, gradient) = sample(warp_sum);
+            result += damped_amplitude * value * value;
+            add_scaled(&mut warp_sum, gradient, self.warp * value);
+            add_scaled(&mut damp_sum, gradient, self.damp * value);
+            // Damp the following layer by the slope so far.
+            amplitude *= self.gain;
+            let slope_squared: f64 = damp_sum.iter().map(|d| d * d).sum();
+            damped_amplitude = amplitude * (1.0 - self.damp_scale / (1.0 + slope_squared));
+        }
+        result
+    }
+}
+
+/// Swiss turbulence fractal, see `SwissBlender`.
+pub type SwissTurbulence<F = Perlin, T = UniformScale<f64>> = GradientFractal<SwissBlender, F, T>;
+
+/// Jordan turbulence fractal, see `JordanBlender`.
+pub type JordanTurbulence<F = Perlin, T = UniformScale<f64>> = GradientFractal<JordanBlender, F, T>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swiss_warps_later_layers_along_the_gradient() {
+        let mut blender = SwissBlender::new(0.5, 2.0);
+        blender.set_lacunarity(3.0);
+        let mut offsets = Vec::new();
+        let result = blender.blend(2, |offset: [f64; 2]| {
+            offsets.push(offset);
+            (0.5, [1.0, -2.0])
+        });
+        // The second layer is displaced by lacunarity * warp * -amplitude * value * gradient.
+        assert_eq!(offsets, vec![[0.0, 0.0], [-3.0, 6.0]]);
+        assert_eq!(result, 0.5 + 0.5 * 0.5 * 0.5);
+
+        // The frequency of the first layer scales the displacement as well.
+        blender.set_frequency(2.0);
+        offsets.clear();
+        blender.blend(2, |offset: [f64; 2]| {
+            offsets.push(offset);
+            (0.5, [1.0, -2.0])
+        });
+        assert_eq!(offsets, vec![[0.0, 0.0], [-6.0, 12.0]]);
+    }
+
+    #[test]
+    fn jordan_second_layer_has_first_gain_times_gain() {
+        let blender = JordanBlender {
+            first_warp: 0.0,
+            warp: 0.0,
+            ..JordanBlender::default()
+        };
+        let result = blender.blend(3, |_: [f64; 2]| (0.5, [0.0, 0.0]));
+        // Without any slope the third layer is fully damped.
+        assert_eq!(result, 0.25 + 0.8 * 0.5 * 0.25);
+    }
+
+    #[test]
+    fn swiss_turbulence_follows_lacunarity_and_frequency() {
+        let swiss = SwissTurbulence::<Perlin>::default()
+            .with_lacunarity(2.5)
+            .with_frequency(1.5);
+        assert_eq!(swiss.blender().lacunarity, 2.5);
+        assert_eq!(swiss.blender().frequency, 1.5);
+    }
+}
@@ -1,14 +1,43 @@
+use crate::{fractals::nyquist_fade, NoiseFn, SamplePoint};
+
 pub use self::{
-    checkerboard::*, constant::*, cylinders::*, open_simplex::*, perlin::*, perlin_surflet::*,
-    super_simplex::*, value::*, worley::*,
+    cell_noise::*, checkerboard::*, constant::*, cubic_value::*, cylinders::*, fade::*,
+    gradient::*, open_simplex::*, perlin::*, perlin_surflet::*, radial::*, spheres::*,
+    super_simplex::*, value::*, waves::*, white_noise::*, worley::*,
 };
 
+mod cell_noise;
 mod checkerboard;
 mod constant;
+mod cubic_value;
 mod cylinders;
+mod fade;
+mod gradient;
 mod open_simplex;
 mod perlin;
 mod perlin_surflet;
+mod radial;
+mod spheres;
 mod super_simplex;
 mod value;
+mod waves;
+mod white_noise;
 mod worley;
+
+/// Filters noise whose features are about one unit apart by fading it towards its mean of zero,
+/// like a single layer of `Fractal`. The noise isn't evaluated once it has faded out completely.
+///
+/// This is how the lattice based generators implement `FilteredNoiseFn`, so they can be filtered
+/// on their own or as the source of modules like `Turbulence`.
+pub(crate) fn filter_lattice_noise<P, F>(noise: &F, point: P, filter_width: f64) -> f64
+where
+    P: SamplePoint,
+    F: NoiseFn<P>,
+{
+    let weight = nyquist_fade(filter_width);
+    if weight > 0.0 {
+        noise.get(point) * weight
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{
+    noise_fns::{NoiseFn, Seedable},
+    permutationtable::{NoiseHasher, PermutationTable},
+};
+
+/// Noise function that outputs a pseudo-random value for every integer lattice cell.
+///
+/// Each point is floored to the lattice cell containing it, and the cell coordinates are hashed,
+/// so every point within a cell produces the same value. This is useful for randomizing
+/// properties per tile or per grid cell.
+///
+/// Output values are evenly distributed between -1.0 and 1.0. Like the other lattice based
+/// generators, the pattern repeats every 256 cells along each axis.
+#[derive(Clone, Copy, Debug)]
+pub struct CellNoise {
+    seed: u32,
+    perm_table: PermutationTable,
+}
+
+impl CellNoise {
+    pub const DEFAULT_SEED: u32 = 0;
+
+    pub fn new(seed: u32) -> Self {
+        Self {
+            seed,
+            perm_table: PermutationTable::new(seed),
+        }
+    }
+}
+
+impl Default for CellNoise {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+impl Seedable for CellNoise {
+    /// Sets the seed value for cell noise
+    fn with_seed(self, seed: u32) -> Self {
+        // If the new seed is the same as the current seed, just return self.
+        if self.seed == seed {
+            return self;
+        }
+
+        // Otherwise, regenerate the permutation table based on the new seed.
+        Self {
+            seed,
+            perm_table: PermutationTable::new(seed),
+        }
+    }
+
+    fn seed(&self) -> u32 {
+        self.seed
+    }
+}
+
+impl<const N: usize> NoiseFn<[f64; N]> for CellNoise {
+    fn get(&self, point: [f64; N]) -> f64 {
+        let mut cell = [0; N];
+        for (cell, coordinate) in cell.iter_mut().zip(&point) {
+            *cell = coordinate.floor() as isize;
+        }
+
+        self.perm_table.hash(&cell) as f64 / 255.0 * 2.0 - 1.0
+    }
+}
+
+impl<const N: usize> NoiseFn<[f32; N], f32> for CellNoise {
+    fn get(&self, point: [f32; N]) -> f32 {
+        let mut cell = [0; N];
+        for (cell, coordinate) in cell.iter_mut().zip(&point) {
+            *cell = coordinate.floor() as isize;
+        }
+
+        self.perm_table.hash(&cell) as f32 / 255.0 * 2.0 - 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CellNoise;
+    use crate::NoiseFn;
+
+    #[test]
+    fn constant_within_cell() {
+        let noise = CellNoise::new(5);
+        let value = noise.get([2.0, -3.0, 7.0]);
+        assert_eq!(noise.get([2.5, -2.25, 7.999]), value);
+        assert_eq!(noise.get([2.999, -2.001, 7.5]), value);
+    }
+}
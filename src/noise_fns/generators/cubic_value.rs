@@ -0,0 +1,169 @@
+use crate::{
+    generators::filter_lattice_noise,
+    math::{self, interpolate},
+    noise_fns::{FilteredNoiseFn, NoiseFn, Seedable},
+    permutationtable::{NoiseHasher, PermutationTable},
+    SamplePoint,
+};
+
+/// Noise function that outputs 2/3/4-dimensional Value noise with cubic interpolation.
+///
+/// Where `Value` fades between the two nearest lattice points along each axis, this function
+/// interpolates through the four nearest lattice points along each axis using cubic
+/// convolution. This makes the first derivative continuous across lattice cells, removing the
+/// square artifacts visible in `Value` at the cost of sampling 4<sup>n</sup> lattice points
+/// instead of 2<sup>n</sup>.
+///
+/// Cubic interpolation overshoots the lattice values it passes through, so the output is not
+/// confined to the [-1, 1] range. The theoretical bound is ±1.5<sup>n</sup> for n dimensions, but
+/// in practice values stay within about ±1.5 in 2D and ±1.7 in 3D. Use `ScaleBias` or `Clamp`
+/// if a strict range is required.
+#[derive(Clone, Copy, Debug)]
+pub struct CubicValue {
+    seed: u32,
+    perm_table: PermutationTable,
+}
+
+impl CubicValue {
+    pub const DEFAULT_SEED: u32 = 0;
+
+    pub fn new() -> Self {
+        Self {
+            seed: Self::DEFAULT_SEED,
+            perm_table: PermutationTable::new(Self::DEFAULT_SEED),
+        }
+    }
+}
+
+impl Default for CubicValue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> FilteredNoiseFn<[f64; N]> for CubicValue
+where
+    Self: NoiseFn<[f64; N]>,
+    [f64; N]: SamplePoint,
+{
+    fn get_filtered(&self, point: [f64; N], filter_width: f64) -> f64 {
+        filter_lattice_noise(self, point, filter_width)
+    }
+}
+
+impl Seedable for CubicValue {
+    /// Sets the seed value for Value noise
+    fn with_seed(self, seed: u32) -> Self {
+        // If the new seed is the same as the current seed, just return self.
+        if self.seed == seed {
+            return self;
+        }
+
+        // Otherwise, regenerate the permutation table based on the new seed.
+        Self {
+            seed,
+            perm_table: PermutationTable::new(seed),
+        }
+    }
+
+    fn seed(&self) -> u32 {
+        self.seed
+    }
+}
+
+/// Interpolates along one axis between the lattice values at `base - 1` to `base + 2`.
+#[inline(always)]
+fn cubic_axis<F>(base: isize, alpha: f64, value_at: F) -> f64
+where
+    F: Fn(isize) -> f64,
+{
+    interpolate::cubic(
+        value_at(base - 1),
+        value_at(base),
+        value_at(base + 1),
+        value_at(base + 2),
+        alpha,
+    )
+}
+
+#[inline(always)]
+fn lattice_value(perm_table: &PermutationTable, corner: &[isize]) -> f64 {
+    perm_table.hash(corner) as f64 / 255.0
+}
+
+/// 2-dimensional cubic value noise
+impl NoiseFn<[f64; 2]> for CubicValue {
+    fn get(&self, point: [f64; 2]) -> f64 {
+        let floored = math::map2(point, f64::floor);
+        let base = math::to_isize2(floored);
+        let alpha = math::sub2(point, floored);
+
+        let d = cubic_axis(base[1], alpha[1], |y| {
+            cubic_axis(base[0], alpha[0], |x| {
+                lattice_value(&self.perm_table, &[x, y])
+            })
+        });
+
+        d * 2.0 - 1.0
+    }
+}
+
+/// 3-dimensional cubic value noise
+impl NoiseFn<[f64; 3]> for CubicValue {
+    fn get(&self, point: [f64; 3]) -> f64 {
+        let floored = math::map3(point, f64::floor);
+        let base = math::to_isize3(floored);
+        let alpha = math::sub3(point, floored);
+
+        let d = cubic_axis(base[2], alpha[2], |z| {
+            cubic_axis(base[1], alpha[1], |y| {
+                cubic_axis(base[0], alpha[0], |x| {
+                    lattice_value(&self.perm_table, &[x, y, z])
+                })
+            })
+        });
+
+        d * 2.0 - 1.0
+    }
+}
+
+/// 4-dimensional cubic value noise
+impl NoiseFn<[f64; 4]> for CubicValue {
+    fn get(&self, point: [f64; 4]) -> f64 {
+        let floored = math::map4(point, f64::floor);
+        let base = math::to_isize4(floored);
+        let alpha = math::sub4(point, floored);
+
+        let d = cubic_axis(base[3], alpha[3], |w| {
+            cubic_axis(base[2], alpha[2], |z| {
+                cubic_axis(base[1], alpha[1], |y| {
+                    cubic_axis(base[0], alpha[0], |x| {
+                        lattice_value(&self.perm_table, &[x, y, z, w])
+                    })
+                })
+            })
+        });
+
+        d * 2.0 - 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generators::Value, NoiseFn, Seedable};
+
+    use super::CubicValue;
+
+    #[test]
+    fn passes_through_lattice_values() {
+        let cubic = CubicValue::new().with_seed(3);
+        let value = Value::new().with_seed(3);
+        assert_eq!(value.with_cubic_interpolation().seed(), cubic.seed());
+        for x in -4..4 {
+            for y in -4..4 {
+                let point = [x as f64, y as f64, (x * y) as f64];
+                assert!((cubic.get(point) - value.get(point)).abs() < 1e-12);
+            }
+        }
+    }
+}
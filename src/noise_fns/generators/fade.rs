@@ -0,0 +1,117 @@
+use crate::math::s_curve::{cubic, quintic};
+use num_traits::Float;
+
+/// Trait for the curves used by lattice based generators to fade between the values at
+/// neighbouring lattice points.
+///
+/// The fade is chosen with a type parameter, so there is no runtime cost for selecting one.
+pub trait Fade {
+    /// Maps a value between 0 and 1 onto the fade curve. The curve must pass through (0, 0)
+    /// and (1, 1).
+    fn fade<F: Float>(t: F) -> F;
+
+    /// The first derivative of `fade`, used to compute analytical gradients.
+    fn fade_derivative<F: Float>(t: F) -> F;
+}
+
+/// Fades linearly between lattice points.
+///
+/// The output is continuous, but its first derivative is not, producing a faceted look which
+/// is useful for low-poly styles.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LinearFade;
+
+impl Fade for LinearFade {
+    #[inline(always)]
+    fn fade<F: Float>(t: F) -> F {
+        t
+    }
+
+    #[inline(always)]
+    fn fade_derivative<F: Float>(_t: F) -> F {
+        F::one()
+    }
+}
+
+/// Fades between lattice points using the cubic Hermite curve -2t<sup>3</sup> + 3t<sup>2</sup>.
+///
+/// The first derivative is continuous, but the second is not. This is the curve libnoise uses
+/// for `NoiseQuality::Standard`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CubicFade;
+
+impl Fade for CubicFade {
+    #[inline(always)]
+    fn fade<F: Float>(t: F) -> F {
+        cubic::cubic(t)
+    }
+
+    #[inline(always)]
+    fn fade_derivative<F: Float>(t: F) -> F {
+        cubic::cubic_derivative(t)
+    }
+}
+
+/// Fades between lattice points using the quintic curve
+/// 6t<sup>5</sup> - 15t<sup>4</sup> + 10t<sup>3</sup>.
+///
+/// Both the first and second derivatives are continuous, which makes this the best choice when
+/// the output is used for lighting or normals. This is the default for all generators.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct QuinticFade;
+
+impl Fade for QuinticFade {
+    #[inline(always)]
+    fn fade<F: Float>(t: F) -> F {
+        quintic::quintic(t)
+    }
+
+    #[inline(always)]
+    fn fade_derivative<F: Float>(t: F) -> F {
+        quintic::quintic_derivative(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generators::{Perlin, Value},
+        NoiseFn, Seedable,
+    };
+
+    fn check_endpoints<S: Fade>() {
+        assert_eq!(S::fade(0.0_f64), 0.0);
+        assert_eq!(S::fade(1.0_f64), 1.0);
+        assert!((S::fade(0.5_f64) - 0.5).abs() < 1e-12);
+        assert!((S::fade(0.25_f32) + S::fade(0.75_f32) - 1.0).abs() < 1e-6);
+        for &t in &[0.1_f64, 0.4, 0.8] {
+            let numerical = (S::fade(t + 1e-6) - S::fade(t - 1e-6)) / 2e-6;
+            assert!((S::fade_derivative(t) - numerical).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn fades_pass_through_endpoints() {
+        check_endpoints::<LinearFade>();
+        check_endpoints::<CubicFade>();
+        check_endpoints::<QuinticFade>();
+    }
+
+    #[test]
+    fn fade_changes_generator_output() {
+        let point = [0.3, 1.7, -2.45];
+        let perlin = Perlin::new(3);
+        let linear = perlin.with_fade::<LinearFade>();
+        assert_ne!(perlin.get(point), linear.get(point));
+
+        let value = Value::new().with_seed(3);
+        let cubic = value.with_fade::<CubicFade>();
+        assert_ne!(value.get(point), cubic.get(point));
+
+        // On lattice points the fade has no effect.
+        let lattice = [2.0, -1.0, 5.0];
+        assert_eq!(perlin.get(lattice), linear.get(lattice));
+        assert_eq!(value.get(lattice), cubic.get(lattice));
+    }
+}
@@ -0,0 +1,103 @@
+use num_traits::Num;
+
+use crate::NoiseFn;
+
+/// Noise function that outputs a linear ramp along one axis.
+///
+/// The output is -1.0 where the coordinate on `axis` equals the lower bound, and rises linearly
+/// to 1.0 where it equals the upper bound. Beyond the bounds, the output is clamped. If both
+/// bounds are equal, the ramp becomes a step from -1.0 to 1.0 at that coordinate.
+#[derive(Clone, Copy, Debug)]
+pub struct Gradient<const N: usize> {
+    /// Index of the coordinate the ramp runs along. Default is 0, the x axis.
+    axis: usize,
+
+    /// Coordinates at which the ramp starts and ends. Default is -1.0 to 1.0.
+    pub bounds: (f64, f64),
+}
+
+impl<const N: usize> Gradient<N> {
+    pub fn new() -> Self {
+        Self {
+            axis: 0,
+            bounds: (-1.0, 1.0),
+        }
+    }
+
+    /// Sets the index of the coordinate the ramp runs along.
+    ///
+    /// # Panics
+    /// Panics if `axis` is not less than the number of dimensions `N`.
+    pub fn with_axis(self, axis: usize) -> Self {
+        assert!(
+            axis < N,
+            "axis {} is out of range for {} dimensions",
+            axis,
+            N
+        );
+
+        Self { axis, ..self }
+    }
+
+    pub fn with_bounds(self, lower_bound: f64, upper_bound: f64) -> Self {
+        Self {
+            bounds: (lower_bound, upper_bound),
+            ..self
+        }
+    }
+
+    /// Returns the index of the coordinate the ramp runs along.
+    pub fn axis(&self) -> usize {
+        self.axis
+    }
+}
+
+impl<const N: usize> Default for Gradient<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E, const N: usize> NoiseFn<[E; N]> for Gradient<N>
+where
+    E: Num + Copy + Into<f64>,
+{
+    fn get(&self, point: [E; N]) -> f64 {
+        let (lower, upper) = self.bounds;
+        let coordinate = point[self.axis].into();
+
+        if lower == upper {
+            return if coordinate < lower { -1.0 } else { 1.0 };
+        }
+
+        let alpha = (coordinate - lower) / (upper - lower);
+
+        (alpha * 2.0 - 1.0).clamp(-1.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ramps_along_axis() {
+        let gradient = Gradient::new().with_axis(1).with_bounds(0.0, 4.0);
+        assert_eq!(gradient.get([9.0, 0.0]), -1.0);
+        assert_eq!(gradient.get([9.0, 1.0]), -0.5);
+        assert_eq!(gradient.get([9.0, 10.0]), 1.0);
+
+        let reversed = gradient.with_bounds(4.0, 0.0);
+        assert_eq!(reversed.get([9.0, 1.0]), 0.5);
+
+        let step = gradient.with_bounds(2.0, 2.0);
+        assert_eq!(step.get([0.0, 1.9]), -1.0);
+        assert_eq!(step.get([0.0, 2.0]), 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn axis_is_checked_at_construction() {
+        Gradient::<2>::new().with_axis(2);
+    }
+}
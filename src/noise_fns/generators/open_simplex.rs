@@ -3,9 +3,10 @@
 //! <http://uniblock.tumblr.com/post/97868843242/noise>
 
 use crate::{
+    generators::filter_lattice_noise,
     gradient, math,
     permutationtable::{NoiseHasher, PermutationTable},
-    NoiseFn, Seedable,
+    FilteredNoiseFn, NoiseFn, SamplePoint, Seedable,
 };
 use std::ops::Add;
 
@@ -44,6 +45,16 @@ impl Default for OpenSimplex {
     }
 }
 
+impl<const N: usize> FilteredNoiseFn<[f64; N]> for OpenSimplex
+where
+    Self: NoiseFn<[f64; N]>,
+    [f64; N]: SamplePoint,
+{
+    fn get_filtered(&self, point: [f64; N], filter_width: f64) -> f64 {
+        filter_lattice_noise(self, point, filter_width)
+    }
+}
+
 impl Seedable for OpenSimplex {
     /// Sets the seed value for Open Simplex noise
     fn with_seed(self, seed: u32) -> Self {
@@ -145,6 +156,16 @@ impl NoiseFn<[f64; 2]> for OpenSimplex {
     }
 }
 
+/// 2-dimensional `OpenSimplex` noise, evaluated in single precision
+///
+/// The kernel is still computed in double precision, so this is no faster than the `f64`
+/// implementation. It exists so that this function can be used in single-precision pipelines.
+impl NoiseFn<[f32; 2], f32> for OpenSimplex {
+    fn get(&self, point: [f32; 2]) -> f32 {
+        NoiseFn::<[f64; 2]>::get(self, math::cast2(point)) as f32
+    }
+}
+
 /// 3-dimensional [`OpenSimplex` Noise](http://uniblock.tumblr.com/post/97868843242/noise)
 ///
 /// This is a slower but higher quality form of gradient noise than `Perlin` 3D.
@@ -279,6 +300,16 @@ impl NoiseFn<[f64; 3]> for OpenSimplex {
     }
 }
 
+/// 3-dimensional `OpenSimplex` noise, evaluated in single precision
+///
+/// The kernel is still computed in double precision, so this is no faster than the `f64`
+/// implementation. It exists so that this function can be used in single-precision pipelines.
+impl NoiseFn<[f32; 3], f32> for OpenSimplex {
+    fn get(&self, point: [f32; 3]) -> f32 {
+        NoiseFn::<[f64; 3]>::get(self, math::cast3(point)) as f32
+    }
+}
+
 /// 4-dimensional [`OpenSimplex` Noise](http://uniblock.tumblr.com/post/97868843242/noise)
 ///
 /// This is a slower but higher quality form of gradient noise than `Perlin` 4D.
@@ -615,3 +646,13 @@ impl NoiseFn<[f64; 4]> for OpenSimplex {
         value * NORM_CONSTANT_4D
     }
 }
+
+/// 4-dimensional `OpenSimplex` noise, evaluated in single precision
+///
+/// The kernel is still computed in double precision, so this is no faster than the `f64`
+/// implementation. It exists so that this function can be used in single-precision pipelines.
+impl NoiseFn<[f32; 4], f32> for OpenSimplex {
+    fn get(&self, point: [f32; 4]) -> f32 {
+        NoiseFn::<[f64; 4]>::get(self, math::cast4(point)) as f32
+    }
+}
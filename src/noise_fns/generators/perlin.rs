@@ -1,14 +1,24 @@
 use crate::{
-    math::{self, s_curve::quintic::Quintic},
-    noise_fns::{NoiseFn, Seedable},
+    math,
+    noise_fns::{
+        generators::{filter_lattice_noise, Fade, QuinticFade},
+        FilteredNoiseFn, GradientNoiseFn, NoiseFn, Seedable,
+    },
     permutationtable::{NoiseHasher, PermutationTable},
+    SamplePoint,
 };
+use num_traits::{AsPrimitive, Float};
+use std::marker::PhantomData;
 
 /// Noise function that outputs 2/3/4-dimensional Perlin noise.
+///
+/// The curve used to fade between lattice points is selected with the `S` type parameter, and
+/// defaults to `QuinticFade`. Use `with_fade` to choose a different one.
 #[derive(Clone, Copy, Debug)]
-pub struct Perlin {
+pub struct Perlin<S = QuinticFade> {
     seed: u32,
     perm_table: PermutationTable,
+    fade: PhantomData<S>,
 }
 
 impl Perlin {
@@ -18,6 +28,19 @@ impl Perlin {
         Self {
             seed,
             perm_table: PermutationTable::new(seed),
+            fade: PhantomData,
+        }
+    }
+}
+
+impl<S> Perlin<S> {
+    /// Returns this noise function modified to use the given curve to fade between lattice
+    /// points.
+    pub fn with_fade<NewS: Fade>(self) -> Perlin<NewS> {
+        Perlin {
+            seed: self.seed,
+            perm_table: self.perm_table,
+            fade: PhantomData,
         }
     }
 }
@@ -28,7 +51,17 @@ impl Default for Perlin {
     }
 }
 
-impl Seedable for Perlin {
+impl<S: Fade, const N: usize> FilteredNoiseFn<[f64; N]> for Perlin<S>
+where
+    Self: NoiseFn<[f64; N]>,
+    [f64; N]: SamplePoint,
+{
+    fn get_filtered(&self, point: [f64; N], filter_width: f64) -> f64 {
+        filter_lattice_noise(self, point, filter_width)
+    }
+}
+
+impl<S> Seedable for Perlin<S> {
     /// Sets the seed value for Perlin noise
     fn with_seed(self, seed: u32) -> Self {
         // If the new seed is the same as the current seed, just return self.
@@ -40,6 +73,7 @@ impl Seedable for Perlin {
         Self {
             seed,
             perm_table: PermutationTable::new(seed),
+            fade: PhantomData,
         }
     }
 
@@ -49,14 +83,25 @@ impl Seedable for Perlin {
 }
 
 /// 2-dimensional perlin noise
-impl NoiseFn<[f64; 2]> for Perlin {
+impl<S: Fade> NoiseFn<[f64; 2]> for Perlin<S> {
     fn get(&self, point: [f64; 2]) -> f64 {
-        perlin_2d(&self.perm_table, point)
+        perlin_2d::<S, _>(&self.perm_table, point)
+    }
+}
+
+/// 2-dimensional perlin noise, evaluated in single precision
+impl<S: Fade> NoiseFn<[f32; 2], f32> for Perlin<S> {
+    fn get(&self, point: [f32; 2]) -> f32 {
+        perlin_2d::<S, _>(&self.perm_table, point)
     }
 }
 
 #[inline(always)]
-pub(crate) fn perlin_2d(hasher: &dyn NoiseHasher, point: [f64; 2]) -> f64 {
+pub(crate) fn perlin_2d<S, F>(hasher: &dyn NoiseHasher, point: [F; 2]) -> F
+where
+    S: Fade,
+    F: Float + AsPrimitive<isize>,
+{
     // Unscaled range of linearly interpolated perlin noise should be (-sqrt(N)/2, sqrt(N)/2).
     // Need to invert this value and multiply the unscaled result by the value to get a scaled
     // range of (-1, 1).
@@ -66,7 +111,7 @@ pub(crate) fn perlin_2d(hasher: &dyn NoiseHasher, point: [f64; 2]) -> f64 {
 
     #[inline(always)]
     #[rustfmt::skip]
-    fn gradient_dot_v(perm: usize, point: [f64; 2]) -> f64 {
+    fn gradient_dot_v<F: Float>(perm: usize, point: [F; 2]) -> F {
         let [x, y] = point;
 
         match perm & 0b11 {
@@ -78,11 +123,11 @@ pub(crate) fn perlin_2d(hasher: &dyn NoiseHasher, point: [f64; 2]) -> f64 {
         }
     }
 
-    let floored = math::map2(point, f64::floor);
+    let floored = math::map2(point, F::floor);
     let corner = math::to_isize2(floored);
     let far_corner = math::add2(corner, [1; 2]);
     let distance = math::sub2(point, floored);
-    let far_distance = math::sub2(distance, [1.0; 2]);
+    let far_distance = math::sub2(distance, [F::one(); 2]);
 
     let g00 = gradient_dot_v(hasher.hash(&corner), distance);
     let g10 = gradient_dot_v(
@@ -95,20 +140,86 @@ pub(crate) fn perlin_2d(hasher: &dyn NoiseHasher, point: [f64; 2]) -> f64 {
     );
     let g11 = gradient_dot_v(hasher.hash(&far_corner), far_distance);
 
-    let [u, v] = distance.map_quintic();
+    let [u, v] = math::map2(distance, S::fade);
 
     let unscaled_result = bilinear_interpolation(u, v, g00, g01, g10, g11);
 
-    let scaled_result = unscaled_result * SCALE_FACTOR;
+    let scaled_result = unscaled_result * F::from(SCALE_FACTOR).unwrap();
 
     // At this point, we should be really damn close to the (-1, 1) range, but some float errors
     // could have accumulated, so let's just clamp the results to (-1, 1) to cut off any
     // outliers and return it.
-    scaled_result.clamp(-1.0, 1.0)
+    scaled_result.max(-F::one()).min(F::one())
+}
+
+/// 2-dimensional perlin noise, with its analytical gradient
+impl<S: Fade> GradientNoiseFn<[f64; 2]> for Perlin<S> {
+    fn get_with_gradient(&self, point: [f64; 2]) -> (f64, [f64; 2]) {
+        perlin_2d_with_gradient::<S>(&self.perm_table, point)
+    }
+}
+
+/// Same as `perlin_2d`, but also returns the gradient of the output. The value is computed from
+/// the same gradient vectors, rather than by calling `perlin_2d`, so that both come from a single
+/// pass over the lattice.
+#[allow(clippy::many_single_char_names)]
+fn perlin_2d_with_gradient<S: Fade>(hasher: &dyn NoiseHasher, point: [f64; 2]) -> (f64, [f64; 2]) {
+    const SCALE_FACTOR: f64 = std::f64::consts::SQRT_2;
+
+    #[inline(always)]
+    #[rustfmt::skip]
+    fn gradient(perm: usize) -> [f64; 2] {
+        match perm & 0b11 {
+            0 => [ 1.0,  1.0],
+            1 => [-1.0,  1.0],
+            2 => [ 1.0, -1.0],
+            3 => [-1.0, -1.0],
+            _ => unreachable!(),
+        }
+    }
+
+    let floored = math::map2(point, f64::floor);
+    let corner = math::to_isize2(floored);
+    let far_corner = math::add2(corner, [1; 2]);
+    let distance = math::sub2(point, floored);
+    let far_distance = math::sub2(distance, [1.0; 2]);
+
+    let v00 = gradient(hasher.hash(&corner));
+    let v10 = gradient(hasher.hash(&[far_corner[0], corner[1]]));
+    let v01 = gradient(hasher.hash(&[corner[0], far_corner[1]]));
+    let v11 = gradient(hasher.hash(&far_corner));
+
+    let g00 = math::dot2(v00, distance);
+    let g10 = math::dot2(v10, [far_distance[0], distance[1]]);
+    let g01 = math::dot2(v01, [distance[0], far_distance[1]]);
+    let g11 = math::dot2(v11, far_distance);
+
+    let [u, v] = math::map2(distance, S::fade);
+    let [du, dv] = math::map2(distance, S::fade_derivative);
+
+    let k1 = g10 - g00;
+    let k2 = g01 - g00;
+    let k3 = g00 + g11 - g10 - g01;
+
+    let value = bilinear_interpolation(u, v, g00, g01, g10, g11);
+
+    // The gradient is the interpolated gradient vectors, plus the change caused by moving the
+    // interpolation weights.
+    let mut gradient = [0.0; 2];
+    for i in 0..2 {
+        gradient[i] = bilinear_interpolation(u, v, v00[i], v01[i], v10[i], v11[i]);
+    }
+    gradient[0] += du * (k1 + k3 * v);
+    gradient[1] += dv * (k2 + k3 * u);
+
+    (
+        (value * SCALE_FACTOR).clamp(-1.0, 1.0),
+        math::mul2(gradient, SCALE_FACTOR),
+    )
 }
 
 #[inline(always)]
-fn bilinear_interpolation(u: f64, v: f64, g00: f64, g01: f64, g10: f64, g11: f64) -> f64 {
+fn bilinear_interpolation<F: Float>(u: F, v: F, g00: F, g01: F, g10: F, g11: F) -> F {
     let k0 = g00;
     let k1 = g10 - g00;
     let k2 = g01 - g00;
@@ -118,15 +229,26 @@ fn bilinear_interpolation(u: f64, v: f64, g00: f64, g01: f64, g10: f64, g11: f64
 }
 
 /// 3-dimensional perlin noise
-impl NoiseFn<[f64; 3]> for Perlin {
+impl<S: Fade> NoiseFn<[f64; 3]> for Perlin<S> {
     fn get(&self, point: [f64; 3]) -> f64 {
-        perlin_3d(&self.perm_table, point)
+        perlin_3d::<S, _>(&self.perm_table, point)
+    }
+}
+
+/// 3-dimensional perlin noise, evaluated in single precision
+impl<S: Fade> NoiseFn<[f32; 3], f32> for Perlin<S> {
+    fn get(&self, point: [f32; 3]) -> f32 {
+        perlin_3d::<S, _>(&self.perm_table, point)
     }
 }
 
 #[inline(always)]
 #[allow(clippy::many_single_char_names)]
-pub(crate) fn perlin_3d(hasher: &dyn NoiseHasher, point: [f64; 3]) -> f64 {
+pub(crate) fn perlin_3d<S, F>(hasher: &dyn NoiseHasher, point: [F; 3]) -> F
+where
+    S: Fade,
+    F: Float + AsPrimitive<isize>,
+{
     // Unscaled range of linearly interpolated perlin noise should be (-sqrt(N)/2, sqrt(N)/2).
     // Need to invert this value and multiply the unscaled result by the value to get a scaled
     // range of (-1, 1).
@@ -139,7 +261,7 @@ pub(crate) fn perlin_3d(hasher: &dyn NoiseHasher, point: [f64; 3]) -> f64 {
 
     #[inline(always)]
     #[rustfmt::skip]
-    fn gradient_dot_v(perm: usize, point: [f64; 3]) -> f64 {
+    fn gradient_dot_v<F: Float>(perm: usize, point: [F; 3]) -> F {
         let [x, y, z] = point;
 
         match perm & 0b1111 {
@@ -159,11 +281,11 @@ pub(crate) fn perlin_3d(hasher: &dyn NoiseHasher, point: [f64; 3]) -> f64 {
         }
     }
 
-    let floored = math::map3(point, f64::floor);
+    let floored = math::map3(point, F::floor);
     let corner = math::to_isize3(floored);
     let far_corner = math::add3(corner, [1; 3]);
     let distance = math::sub3(point, floored);
-    let far_distance = math::sub3(distance, [1.0; 3]);
+    let far_distance = math::sub3(distance, [F::one(); 3]);
 
     let g000 = gradient_dot_v(hasher.hash(&corner), distance);
     let g100 = gradient_dot_v(
@@ -192,7 +314,7 @@ pub(crate) fn perlin_3d(hasher: &dyn NoiseHasher, point: [f64; 3]) -> f64 {
     );
     let g111 = gradient_dot_v(hasher.hash(&far_corner), far_distance);
 
-    let [a, b, c] = distance.map_quintic();
+    let [a, b, c] = math::map3(distance, S::fade);
 
     let k0 = g000;
     let k1 = g100 - g000;
@@ -206,32 +328,155 @@ pub(crate) fn perlin_3d(hasher: &dyn NoiseHasher, point: [f64; 3]) -> f64 {
     let unscaled_result =
         k0 + k1 * a + k2 * b + k3 * c + k4 * a * b + k5 * a * c + k6 * b * c + k7 * a * b * c;
 
-    let scaled_result = unscaled_result * SCALE_FACTOR;
+    let scaled_result = unscaled_result * F::from(SCALE_FACTOR).unwrap();
 
     // At this point, we should be really damn close to the (-1, 1) range, but some float errors
     // could have accumulated, so let's just clamp the results to (-1, 1) to cut off any
     // outliers and return it.
-    scaled_result.clamp(-1.0, 1.0)
+    scaled_result.max(-F::one()).min(F::one())
+}
+
+/// 3-dimensional perlin noise, with its analytical gradient
+impl<S: Fade> GradientNoiseFn<[f64; 3]> for Perlin<S> {
+    fn get_with_gradient(&self, point: [f64; 3]) -> (f64, [f64; 3]) {
+        perlin_3d_with_gradient::<S>(&self.perm_table, point)
+    }
+}
+
+/// Same as `perlin_3d`, but also returns the gradient of the output.
+#[allow(clippy::many_single_char_names)]
+fn perlin_3d_with_gradient<S: Fade>(hasher: &dyn NoiseHasher, point: [f64; 3]) -> (f64, [f64; 3]) {
+    // 2/sqrt(3), see perlin_3d.
+    const SCALE_FACTOR: f64 = 1.154_700_538_379_251_5;
+
+    #[inline(always)]
+    #[rustfmt::skip]
+    fn gradient(perm: usize) -> [f64; 3] {
+        match perm & 0b1111 {
+            0  | 12 => [ 1.0,  1.0,  0.0],
+            1  | 13 => [-1.0,  1.0,  0.0],
+            2       => [ 1.0, -1.0,  0.0],
+            3       => [-1.0, -1.0,  0.0],
+            4       => [ 1.0,  0.0,  1.0],
+            5       => [-1.0,  0.0,  1.0],
+            6       => [ 1.0,  0.0, -1.0],
+            7       => [-1.0,  0.0, -1.0],
+            8       => [ 0.0,  1.0,  1.0],
+            9  | 14 => [ 0.0, -1.0,  1.0],
+            10      => [ 0.0,  1.0, -1.0],
+            11 | 15 => [ 0.0, -1.0, -1.0],
+            _ => unreachable!(),
+        }
+    }
+
+    let floored = math::map3(point, f64::floor);
+    let corner = math::to_isize3(floored);
+    let far_corner = math::add3(corner, [1; 3]);
+    let distance = math::sub3(point, floored);
+    let far_distance = math::sub3(distance, [1.0; 3]);
+
+    // Gradient vectors and distances for each corner, indexed by [x][y][z] offset.
+    let mut vectors = [[[[0.0; 3]; 2]; 2]; 2];
+    let mut dots = [[[0.0; 2]; 2]; 2];
+    let corners = [corner, far_corner];
+    let distances = [distance, far_distance];
+    for i in 0..2 {
+        for j in 0..2 {
+            for k in 0..2 {
+                let vector = gradient(hasher.hash(&[corners[i][0], corners[j][1], corners[k][2]]));
+                vectors[i][j][k] = vector;
+                dots[i][j][k] =
+                    math::dot3(vector, [distances[i][0], distances[j][1], distances[k][2]]);
+            }
+        }
+    }
+
+    let [a, b, c] = math::map3(distance, S::fade);
+    let [da, db, dc] = math::map3(distance, S::fade_derivative);
+
+    // Coefficients of the trilinear interpolation, in the same order as perlin_3d.
+    let coefficients = |g: [[[f64; 2]; 2]; 2]| {
+        [
+            g[0][0][0],
+            g[1][0][0] - g[0][0][0],
+            g[0][1][0] - g[0][0][0],
+            g[0][0][1] - g[0][0][0],
+            g[0][0][0] + g[1][1][0] - g[1][0][0] - g[0][1][0],
+            g[0][0][0] + g[1][0][1] - g[1][0][0] - g[0][0][1],
+            g[0][0][0] + g[0][1][1] - g[0][1][0] - g[0][0][1],
+            g[1][0][0] + g[0][1][0] + g[0][0][1] + g[1][1][1]
+                - g[0][0][0]
+                - g[1][1][0]
+                - g[1][0][1]
+                - g[0][1][1],
+        ]
+    };
+    let interpolate = |k: [f64; 8]| {
+        k[0] + k[1] * a
+            + k[2] * b
+            + k[3] * c
+            + k[4] * a * b
+            + k[5] * a * c
+            + k[6] * b * c
+            + k[7] * a * b * c
+    };
+
+    let k = coefficients(dots);
+    let value = interpolate(k);
+
+    // The gradient is the interpolated gradient vectors, plus the change caused by moving the
+    // interpolation weights.
+    let mut gradient = [0.0; 3];
+    for (axis, component) in gradient.iter_mut().enumerate() {
+        let mut g = [[[0.0; 2]; 2]; 2];
+        for i in 0..2 {
+            for j in 0..2 {
+                for k in 0..2 {
+                    g[i][j][k] = vectors[i][j][k][axis];
+                }
+            }
+        }
+        *component = interpolate(coefficients(g));
+    }
+    gradient[0] += da * (k[1] + k[4] * b + k[5] * c + k[7] * b * c);
+    gradient[1] += db * (k[2] + k[4] * a + k[6] * c + k[7] * a * c);
+    gradient[2] += dc * (k[3] + k[5] * a + k[6] * b + k[7] * a * b);
+
+    (
+        (value * SCALE_FACTOR).clamp(-1.0, 1.0),
+        math::mul3(gradient, SCALE_FACTOR),
+    )
 }
 
 /// 4-dimensional perlin noise
-impl NoiseFn<[f64; 4]> for Perlin {
+impl<S: Fade> NoiseFn<[f64; 4]> for Perlin<S> {
     fn get(&self, point: [f64; 4]) -> f64 {
-        perlin_4d(&self.perm_table, point)
+        perlin_4d::<S, _>(&self.perm_table, point)
+    }
+}
+
+/// 4-dimensional perlin noise, evaluated in single precision
+impl<S: Fade> NoiseFn<[f32; 4], f32> for Perlin<S> {
+    fn get(&self, point: [f32; 4]) -> f32 {
+        perlin_4d::<S, _>(&self.perm_table, point)
     }
 }
 
 #[inline(always)]
 #[rustfmt::skip]
 #[allow(clippy::many_single_char_names)]
-pub(crate) fn perlin_4d(hasher: &dyn NoiseHasher, point: [f64; 4]) -> f64 {
+pub(crate) fn perlin_4d<S, F>(hasher: &dyn NoiseHasher, point: [F; 4]) -> F
+where
+    S: Fade,
+    F: Float + AsPrimitive<isize>,
+{
     // Unscaled range of linearly interpolated perlin noise should be (-sqrt(N)/2, sqrt(N)/2).
     // Need to invert this value and multiply the unscaled result by the value to get a scaled
     // range of (-1, 1).
     const SCALE_FACTOR: f64 = 1.0; // 1/(sqrt(N)/2), N=4 -> 2/sqrt(4) -> 2/2 -> 1
 
     #[inline(always)]
-    fn gradient_dot_v(perm: usize, point: [f64; 4]) -> f64 {
+    fn gradient_dot_v<F: Float>(perm: usize, point: [F; 4]) -> F {
         let [x, y, z, w] = point;
 
         match perm & 0b11111 {
@@ -267,11 +512,11 @@ pub(crate) fn perlin_4d(hasher: &dyn NoiseHasher, point: [f64; 4]) -> f64 {
         }
     }
 
-    let floored = math::map4(point, f64::floor);
+    let floored = math::map4(point, F::floor);
     let corner = math::to_isize4(floored);
     let far_corner = math::add4(corner, [1; 4]);
     let distance = math::sub4(point, floored);
-    let far_distance = math::sub4(distance, [1.0; 4]);
+    let far_distance = math::sub4(distance, [F::one(); 4]);
 
     let g0000 = gradient_dot_v(
         hasher.hash(&corner),
@@ -453,7 +698,7 @@ pub(crate) fn perlin_4d(hasher: &dyn NoiseHasher, point: [f64; 4]) -> f64 {
         far_distance[3]],
     );
 
-    let [a, b, c, d] = distance.map_quintic();
+    let [a, b, c, d] = math::map4(distance, S::fade);
 
     let k0 = g0000;
     let k1 = g1000 - g0000;
@@ -489,10 +734,74 @@ pub(crate) fn perlin_4d(hasher: &dyn NoiseHasher, point: [f64; 4]) -> f64 {
         + k14 * b * c * d
         + k15 * a * b * c * d;
 
-    let scaled_result = unscaled_result * SCALE_FACTOR;
+    let scaled_result = unscaled_result * F::from(SCALE_FACTOR).unwrap();
 
     // At this point, we should be really damn close to the (-1, 1) range, but some float errors
     // could have accumulated, so let's just clamp the results to (-1, 1) to cut off any
     // outliers and return it.
-    scaled_result.clamp(-1.0, 1.0)
+    scaled_result.max(-F::one()).min(F::one())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Perlin;
+    use crate::{transformers::Turbulence, FilteredNoiseFn, GradientNoiseFn, NoiseFn};
+
+    #[test]
+    fn single_precision_matches_double_precision() {
+        let perlin = Perlin::new(7);
+        for i in 0..64 {
+            let x = i as f64 * 0.37 - 11.0;
+            let y = i as f64 * 0.91 + 3.0;
+            let double: f64 = perlin.get([x, y, x * y]);
+            let single: f32 = perlin.get([x as f32, y as f32, (x * y) as f32]);
+            assert!((double - single as f64).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn gradient_matches_finite_differences() {
+        const H: f64 = 1e-6;
+        let perlin = Perlin::new(3);
+        for i in 0..64 {
+            let point = [
+                i as f64 * 0.37 - 11.0,
+                i as f64 * 0.91 + 3.0,
+                i as f64 * 0.13,
+            ];
+            let (value, gradient) = perlin.get_with_gradient(point);
+            assert!((value - perlin.get(point)).abs() < 1e-12);
+            for axis in 0..3 {
+                let (mut ahead, mut behind) = (point, point);
+                ahead[axis] += H;
+                behind[axis] -= H;
+                let numerical = (perlin.get(ahead) - perlin.get(behind)) / (2.0 * H);
+                assert!((gradient[axis] - numerical).abs() < 1e-4);
+            }
+
+            let point = [point[0], point[1]];
+            let (value, gradient) = perlin.get_with_gradient(point);
+            assert!((value - perlin.get(point)).abs() < 1e-12);
+            for axis in 0..2 {
+                let (mut ahead, mut behind) = (point, point);
+                ahead[axis] += H;
+                behind[axis] -= H;
+                let numerical = (perlin.get(ahead) - perlin.get(behind)) / (2.0 * H);
+                assert!((gradient[axis] - numerical).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn filtering_fades_out_near_nyquist() {
+        let perlin = Perlin::new(5);
+        let point = [0.3, -1.7, 2.2];
+        assert_eq!(perlin.get_filtered(point, 0.1), perlin.get(point));
+        assert_eq!(perlin.get_filtered(point, 0.375), 0.5 * perlin.get(point));
+        assert_eq!(perlin.get_filtered(point, 0.5), 0.0);
+
+        // Generators can now be filtered as the source of other modules.
+        let turbulence = Turbulence::new(perlin);
+        assert_eq!(turbulence.get_filtered(point, 0.5), 0.0);
+    }
 }
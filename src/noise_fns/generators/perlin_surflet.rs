@@ -1,8 +1,11 @@
 use crate::{
+    generators::filter_lattice_noise,
     gradient, math,
-    noise_fns::{NoiseFn, Seedable},
+    noise_fns::{FilteredNoiseFn, NoiseFn, Seedable},
     permutationtable::{NoiseHasher, PermutationTable},
+    SamplePoint,
 };
+use num_traits::{AsPrimitive, Float};
 
 /// Noise function that outputs 2/3/4-dimensional Perlin noise.
 ///
@@ -31,6 +34,16 @@ impl Default for PerlinSurflet {
     }
 }
 
+impl<const N: usize> FilteredNoiseFn<[f64; N]> for PerlinSurflet
+where
+    Self: NoiseFn<[f64; N]>,
+    [f64; N]: SamplePoint,
+{
+    fn get_filtered(&self, point: [f64; N], filter_width: f64) -> f64 {
+        filter_lattice_noise(self, point, filter_width)
+    }
+}
+
 impl Seedable for PerlinSurflet {
     /// Sets the seed value for Perlin noise
     fn with_seed(self, seed: u32) -> Self {
@@ -54,342 +67,401 @@ impl Seedable for PerlinSurflet {
 /// 2-dimensional perlin noise
 impl NoiseFn<[f64; 2]> for PerlinSurflet {
     fn get(&self, point: [f64; 2]) -> f64 {
-        const SCALE_FACTOR: f64 = 3.160_493_827_160_493_7;
+        perlin_surflet_2d(&self.perm_table, point)
+    }
+}
+
+/// 2-dimensional perlin noise, evaluated in single precision
+impl NoiseFn<[f32; 2], f32> for PerlinSurflet {
+    fn get(&self, point: [f32; 2]) -> f32 {
+        perlin_surflet_2d(&self.perm_table, point)
+    }
+}
+
+#[inline(always)]
+fn perlin_surflet_2d<F>(hasher: &dyn NoiseHasher, point: [F; 2]) -> F
+where
+    F: Float + AsPrimitive<isize>,
+{
+    const SCALE_FACTOR: f64 = 3.160_493_827_160_493_7;
 
-        #[inline(always)]
-        fn surflet(perm_table: &PermutationTable, corner: [isize; 2], distance: [f64; 2]) -> f64 {
-            let attn = 1.0 - math::dot2(distance, distance);
-            if attn > 0.0 {
-                attn.powi(4) * math::dot2(distance, gradient::get2(perm_table.hash(&corner)))
-            } else {
-                0.0
-            }
+    #[inline(always)]
+    fn surflet<F: Float>(hasher: &dyn NoiseHasher, corner: [isize; 2], distance: [F; 2]) -> F {
+        let attn = F::one() - math::dot2(distance, distance);
+        if attn > F::zero() {
+            let gradient = math::map2(gradient::get2(hasher.hash(&corner)), |v| {
+                F::from(v).unwrap()
+            });
+            attn.powi(4) * math::dot2(distance, gradient)
+        } else {
+            F::zero()
         }
+    }
 
-        let floored = math::map2(point, f64::floor);
-        let near_corner = math::to_isize2(floored);
-        let far_corner = math::add2(near_corner, math::one2());
-        let near_distance = math::sub2(point, floored);
-        let far_distance = math::sub2(near_distance, math::one2());
+    let floored = math::map2(point, F::floor);
+    let near_corner = math::to_isize2(floored);
+    let far_corner = math::add2(near_corner, [1; 2]);
+    let near_distance = math::sub2(point, floored);
+    let far_distance = math::sub2(near_distance, [F::one(); 2]);
 
-        let f00 = surflet(
-            &self.perm_table,
-            [near_corner[0], near_corner[1]],
-            [near_distance[0], near_distance[1]],
-        );
-        let f10 = surflet(
-            &self.perm_table,
-            [far_corner[0], near_corner[1]],
-            [far_distance[0], near_distance[1]],
-        );
-        let f01 = surflet(
-            &self.perm_table,
-            [near_corner[0], far_corner[1]],
-            [near_distance[0], far_distance[1]],
-        );
-        let f11 = surflet(
-            &self.perm_table,
-            [far_corner[0], far_corner[1]],
-            [far_distance[0], far_distance[1]],
-        );
+    let f00 = surflet(
+        hasher,
+        [near_corner[0], near_corner[1]],
+        [near_distance[0], near_distance[1]],
+    );
+    let f10 = surflet(
+        hasher,
+        [far_corner[0], near_corner[1]],
+        [far_distance[0], near_distance[1]],
+    );
+    let f01 = surflet(
+        hasher,
+        [near_corner[0], far_corner[1]],
+        [near_distance[0], far_distance[1]],
+    );
+    let f11 = surflet(
+        hasher,
+        [far_corner[0], far_corner[1]],
+        [far_distance[0], far_distance[1]],
+    );
 
-        // Multiply by arbitrary value to scale to -1..1
-        ((f00 + f10 + f01 + f11) * SCALE_FACTOR).clamp(-1.0, 1.0)
-    }
+    // Multiply by arbitrary value to scale to -1..1
+    ((f00 + f10 + f01 + f11) * F::from(SCALE_FACTOR).unwrap())
+        .max(-F::one())
+        .min(F::one())
 }
 
 /// 3-dimensional perlin noise
 impl NoiseFn<[f64; 3]> for PerlinSurflet {
     fn get(&self, point: [f64; 3]) -> f64 {
-        const SCALE_FACTOR: f64 = 3.889_855_325_553_107_4;
+        perlin_surflet_3d(&self.perm_table, point)
+    }
+}
 
-        #[inline(always)]
-        fn surflet(perm_table: &PermutationTable, corner: [isize; 3], distance: [f64; 3]) -> f64 {
-            let attn = 1.0 - math::dot3(distance, distance);
-            if attn > 0.0 {
-                attn.powi(4) * math::dot3(distance, gradient::get3(perm_table.hash(&corner)))
-            } else {
-                0.0
-            }
+/// 3-dimensional perlin noise, evaluated in single precision
+impl NoiseFn<[f32; 3], f32> for PerlinSurflet {
+    fn get(&self, point: [f32; 3]) -> f32 {
+        perlin_surflet_3d(&self.perm_table, point)
+    }
+}
+
+#[inline(always)]
+fn perlin_surflet_3d<F>(hasher: &dyn NoiseHasher, point: [F; 3]) -> F
+where
+    F: Float + AsPrimitive<isize>,
+{
+    const SCALE_FACTOR: f64 = 3.889_855_325_553_107_4;
+
+    #[inline(always)]
+    fn surflet<F: Float>(hasher: &dyn NoiseHasher, corner: [isize; 3], distance: [F; 3]) -> F {
+        let attn = F::one() - math::dot3(distance, distance);
+        if attn > F::zero() {
+            let gradient = math::map3(gradient::get3(hasher.hash(&corner)), |v| {
+                F::from(v).unwrap()
+            });
+            attn.powi(4) * math::dot3(distance, gradient)
+        } else {
+            F::zero()
         }
+    }
 
-        let floored = math::map3(point, f64::floor);
-        let near_corner = math::to_isize3(floored);
-        let far_corner = math::add3(near_corner, math::one3());
-        let near_distance = math::sub3(point, floored);
-        let far_distance = math::sub3(near_distance, math::one3());
+    let floored = math::map3(point, F::floor);
+    let near_corner = math::to_isize3(floored);
+    let far_corner = math::add3(near_corner, [1; 3]);
+    let near_distance = math::sub3(point, floored);
+    let far_distance = math::sub3(near_distance, [F::one(); 3]);
 
-        let f000 = surflet(
-            &self.perm_table,
-            [near_corner[0], near_corner[1], near_corner[2]],
-            [near_distance[0], near_distance[1], near_distance[2]],
-        );
-        let f100 = surflet(
-            &self.perm_table,
-            [far_corner[0], near_corner[1], near_corner[2]],
-            [far_distance[0], near_distance[1], near_distance[2]],
-        );
-        let f010 = surflet(
-            &self.perm_table,
-            [near_corner[0], far_corner[1], near_corner[2]],
-            [near_distance[0], far_distance[1], near_distance[2]],
-        );
-        let f110 = surflet(
-            &self.perm_table,
-            [far_corner[0], far_corner[1], near_corner[2]],
-            [far_distance[0], far_distance[1], near_distance[2]],
-        );
-        let f001 = surflet(
-            &self.perm_table,
-            [near_corner[0], near_corner[1], far_corner[2]],
-            [near_distance[0], near_distance[1], far_distance[2]],
-        );
-        let f101 = surflet(
-            &self.perm_table,
-            [far_corner[0], near_corner[1], far_corner[2]],
-            [far_distance[0], near_distance[1], far_distance[2]],
-        );
-        let f011 = surflet(
-            &self.perm_table,
-            [near_corner[0], far_corner[1], far_corner[2]],
-            [near_distance[0], far_distance[1], far_distance[2]],
-        );
-        let f111 = surflet(
-            &self.perm_table,
-            [far_corner[0], far_corner[1], far_corner[2]],
-            [far_distance[0], far_distance[1], far_distance[2]],
-        );
+    let f000 = surflet(
+        hasher,
+        [near_corner[0], near_corner[1], near_corner[2]],
+        [near_distance[0], near_distance[1], near_distance[2]],
+    );
+    let f100 = surflet(
+        hasher,
+        [far_corner[0], near_corner[1], near_corner[2]],
+        [far_distance[0], near_distance[1], near_distance[2]],
+    );
+    let f010 = surflet(
+        hasher,
+        [near_corner[0], far_corner[1], near_corner[2]],
+        [near_distance[0], far_distance[1], near_distance[2]],
+    );
+    let f110 = surflet(
+        hasher,
+        [far_corner[0], far_corner[1], near_corner[2]],
+        [far_distance[0], far_distance[1], near_distance[2]],
+    );
+    let f001 = surflet(
+        hasher,
+        [near_corner[0], near_corner[1], far_corner[2]],
+        [near_distance[0], near_distance[1], far_distance[2]],
+    );
+    let f101 = surflet(
+        hasher,
+        [far_corner[0], near_corner[1], far_corner[2]],
+        [far_distance[0], near_distance[1], far_distance[2]],
+    );
+    let f011 = surflet(
+        hasher,
+        [near_corner[0], far_corner[1], far_corner[2]],
+        [near_distance[0], far_distance[1], far_distance[2]],
+    );
+    let f111 = surflet(
+        hasher,
+        [far_corner[0], far_corner[1], far_corner[2]],
+        [far_distance[0], far_distance[1], far_distance[2]],
+    );
 
-        // Multiply by arbitrary value to scale to -1..1
-        ((f000 + f100 + f010 + f110 + f001 + f101 + f011 + f111) * SCALE_FACTOR).clamp(-1.0, 1.0)
-    }
+    // Multiply by arbitrary value to scale to -1..1
+    ((f000 + f100 + f010 + f110 + f001 + f101 + f011 + f111) * F::from(SCALE_FACTOR).unwrap())
+        .max(-F::one())
+        .min(F::one())
 }
 
 /// 4-dimensional perlin noise
 impl NoiseFn<[f64; 4]> for PerlinSurflet {
     fn get(&self, point: [f64; 4]) -> f64 {
-        const SCALE_FACTOR: f64 = 4.424_369_240_215_691;
+        perlin_surflet_4d(&self.perm_table, point)
+    }
+}
 
-        #[inline(always)]
-        fn surflet(perm_table: &PermutationTable, corner: [isize; 4], distance: [f64; 4]) -> f64 {
-            let attn = 1.0 - math::dot4(distance, distance);
-            if attn > 0.0 {
-                attn.powi(4) * math::dot4(distance, gradient::get4(perm_table.hash(&corner)))
-            } else {
-                0.0
-            }
+/// 4-dimensional perlin noise, evaluated in single precision
+impl NoiseFn<[f32; 4], f32> for PerlinSurflet {
+    fn get(&self, point: [f32; 4]) -> f32 {
+        perlin_surflet_4d(&self.perm_table, point)
+    }
+}
+
+#[inline(always)]
+fn perlin_surflet_4d<F>(hasher: &dyn NoiseHasher, point: [F; 4]) -> F
+where
+    F: Float + AsPrimitive<isize>,
+{
+    const SCALE_FACTOR: f64 = 4.424_369_240_215_691;
+
+    #[inline(always)]
+    fn surflet<F: Float>(hasher: &dyn NoiseHasher, corner: [isize; 4], distance: [F; 4]) -> F {
+        let attn = F::one() - math::dot4(distance, distance);
+        if attn > F::zero() {
+            let gradient = math::map4(gradient::get4(hasher.hash(&corner)), |v| {
+                F::from(v).unwrap()
+            });
+            attn.powi(4) * math::dot4(distance, gradient)
+        } else {
+            F::zero()
         }
+    }
 
-        let floored = math::map4(point, f64::floor);
-        let near_corner = math::to_isize4(floored);
-        let far_corner = math::add4(near_corner, math::one4());
-        let near_distance = math::sub4(point, floored);
-        let far_distance = math::sub4(near_distance, math::one4());
+    let floored = math::map4(point, F::floor);
+    let near_corner = math::to_isize4(floored);
+    let far_corner = math::add4(near_corner, [1; 4]);
+    let near_distance = math::sub4(point, floored);
+    let far_distance = math::sub4(near_distance, [F::one(); 4]);
 
-        let f0000 = surflet(
-            &self.perm_table,
-            [
-                near_corner[0],
-                near_corner[1],
-                near_corner[2],
-                near_corner[3],
-            ],
-            [
-                near_distance[0],
-                near_distance[1],
-                near_distance[2],
-                near_distance[3],
-            ],
-        );
-        let f1000 = surflet(
-            &self.perm_table,
-            [
-                far_corner[0],
-                near_corner[1],
-                near_corner[2],
-                near_corner[3],
-            ],
-            [
-                far_distance[0],
-                near_distance[1],
-                near_distance[2],
-                near_distance[3],
-            ],
-        );
-        let f0100 = surflet(
-            &self.perm_table,
-            [
-                near_corner[0],
-                far_corner[1],
-                near_corner[2],
-                near_corner[3],
-            ],
-            [
-                near_distance[0],
-                far_distance[1],
-                near_distance[2],
-                near_distance[3],
-            ],
-        );
-        let f1100 = surflet(
-            &self.perm_table,
-            [far_corner[0], far_corner[1], near_corner[2], near_corner[3]],
-            [
-                far_distance[0],
-                far_distance[1],
-                near_distance[2],
-                near_distance[3],
-            ],
-        );
-        let f0010 = surflet(
-            &self.perm_table,
-            [
-                near_corner[0],
-                near_corner[1],
-                far_corner[2],
-                near_corner[3],
-            ],
-            [
-                near_distance[0],
-                near_distance[1],
-                far_distance[2],
-                near_distance[3],
-            ],
-        );
-        let f1010 = surflet(
-            &self.perm_table,
-            [far_corner[0], near_corner[1], far_corner[2], near_corner[3]],
-            [
-                far_distance[0],
-                near_distance[1],
-                far_distance[2],
-                near_distance[3],
-            ],
-        );
-        let f0110 = surflet(
-            &self.perm_table,
-            [near_corner[0], far_corner[1], far_corner[2], near_corner[3]],
-            [
-                near_distance[0],
-                far_distance[1],
-                far_distance[2],
-                near_distance[3],
-            ],
-        );
-        let f1110 = surflet(
-            &self.perm_table,
-            [far_corner[0], far_corner[1], far_corner[2], near_corner[3]],
-            [
-                far_distance[0],
-                far_distance[1],
-                far_distance[2],
-                near_distance[3],
-            ],
-        );
-        let f0001 = surflet(
-            &self.perm_table,
-            [
-                near_corner[0],
-                near_corner[1],
-                near_corner[2],
-                far_corner[3],
-            ],
-            [
-                near_distance[0],
-                near_distance[1],
-                near_distance[2],
-                far_distance[3],
-            ],
-        );
-        let f1001 = surflet(
-            &self.perm_table,
-            [far_corner[0], near_corner[1], near_corner[2], far_corner[3]],
-            [
-                far_distance[0],
-                near_distance[1],
-                near_distance[2],
-                far_distance[3],
-            ],
-        );
-        let f0101 = surflet(
-            &self.perm_table,
-            [near_corner[0], far_corner[1], near_corner[2], far_corner[3]],
-            [
-                near_distance[0],
-                far_distance[1],
-                near_distance[2],
-                far_distance[3],
-            ],
-        );
-        let f1101 = surflet(
-            &self.perm_table,
-            [far_corner[0], far_corner[1], near_corner[2], far_corner[3]],
-            [
-                far_distance[0],
-                far_distance[1],
-                near_distance[2],
-                far_distance[3],
-            ],
-        );
-        let f0011 = surflet(
-            &self.perm_table,
-            [near_corner[0], near_corner[1], far_corner[2], far_corner[3]],
-            [
-                near_distance[0],
-                near_distance[1],
-                far_distance[2],
-                far_distance[3],
-            ],
-        );
-        let f1011 = surflet(
-            &self.perm_table,
-            [far_corner[0], near_corner[1], far_corner[2], far_corner[3]],
-            [
-                far_distance[0],
-                near_distance[1],
-                far_distance[2],
-                far_distance[3],
-            ],
-        );
-        let f0111 = surflet(
-            &self.perm_table,
-            [near_corner[0], far_corner[1], far_corner[2], far_corner[3]],
-            [
-                near_distance[0],
-                far_distance[1],
-                far_distance[2],
-                far_distance[3],
-            ],
-        );
-        let f1111 = surflet(
-            &self.perm_table,
-            [far_corner[0], far_corner[1], far_corner[2], far_corner[3]],
-            [
-                far_distance[0],
-                far_distance[1],
-                far_distance[2],
-                far_distance[3],
-            ],
-        );
+    let f0000 = surflet(
+        hasher,
+        [
+            near_corner[0],
+            near_corner[1],
+            near_corner[2],
+            near_corner[3],
+        ],
+        [
+            near_distance[0],
+            near_distance[1],
+            near_distance[2],
+            near_distance[3],
+        ],
+    );
+    let f1000 = surflet(
+        hasher,
+        [
+            far_corner[0],
+            near_corner[1],
+            near_corner[2],
+            near_corner[3],
+        ],
+        [
+            far_distance[0],
+            near_distance[1],
+            near_distance[2],
+            near_distance[3],
+        ],
+    );
+    let f0100 = surflet(
+        hasher,
+        [
+            near_corner[0],
+            far_corner[1],
+            near_corner[2],
+            near_corner[3],
+        ],
+        [
+            near_distance[0],
+            far_distance[1],
+            near_distance[2],
+            near_distance[3],
+        ],
+    );
+    let f1100 = surflet(
+        hasher,
+        [far_corner[0], far_corner[1], near_corner[2], near_corner[3]],
+        [
+            far_distance[0],
+            far_distance[1],
+            near_distance[2],
+            near_distance[3],
+        ],
+    );
+    let f0010 = surflet(
+        hasher,
+        [
+            near_corner[0],
+            near_corner[1],
+            far_corner[2],
+            near_corner[3],
+        ],
+        [
+            near_distance[0],
+            near_distance[1],
+            far_distance[2],
+            near_distance[3],
+        ],
+    );
+    let f1010 = surflet(
+        hasher,
+        [far_corner[0], near_corner[1], far_corner[2], near_corner[3]],
+        [
+            far_distance[0],
+            near_distance[1],
+            far_distance[2],
+            near_distance[3],
+        ],
+    );
+    let f0110 = surflet(
+        hasher,
+        [near_corner[0], far_corner[1], far_corner[2], near_corner[3]],
+        [
+            near_distance[0],
+            far_distance[1],
+            far_distance[2],
+            near_distance[3],
+        ],
+    );
+    let f1110 = surflet(
+        hasher,
+        [far_corner[0], far_corner[1], far_corner[2], near_corner[3]],
+        [
+            far_distance[0],
+            far_distance[1],
+            far_distance[2],
+            near_distance[3],
+        ],
+    );
+    let f0001 = surflet(
+        hasher,
+        [
+            near_corner[0],
+            near_corner[1],
+            near_corner[2],
+            far_corner[3],
+        ],
+        [
+            near_distance[0],
+            near_distance[1],
+            near_distance[2],
+            far_distance[3],
+        ],
+    );
+    let f1001 = surflet(
+        hasher,
+        [far_corner[0], near_corner[1], near_corner[2], far_corner[3]],
+        [
+            far_distance[0],
+            near_distance[1],
+            near_distance[2],
+            far_distance[3],
+        ],
+    );
+    let f0101 = surflet(
+        hasher,
+        [near_corner[0], far_corner[1], near_corner[2], far_corner[3]],
+        [
+            near_distance[0],
+            far_distance[1],
+            near_distance[2],
+            far_distance[3],
+        ],
+    );
+    let f1101 = surflet(
+        hasher,
+        [far_corner[0], far_corner[1], near_corner[2], far_corner[3]],
+        [
+            far_distance[0],
+            far_distance[1],
+            near_distance[2],
+            far_distance[3],
+        ],
+    );
+    let f0011 = surflet(
+        hasher,
+        [near_corner[0], near_corner[1], far_corner[2], far_corner[3]],
+        [
+            near_distance[0],
+            near_distance[1],
+            far_distance[2],
+            far_distance[3],
+        ],
+    );
+    let f1011 = surflet(
+        hasher,
+        [far_corner[0], near_corner[1], far_corner[2], far_corner[3]],
+        [
+            far_distance[0],
+            near_distance[1],
+            far_distance[2],
+            far_distance[3],
+        ],
+    );
+    let f0111 = surflet(
+        hasher,
+        [near_corner[0], far_corner[1], far_corner[2], far_corner[3]],
+        [
+            near_distance[0],
+            far_distance[1],
+            far_distance[2],
+            far_distance[3],
+        ],
+    );
+    let f1111 = surflet(
+        hasher,
+        [far_corner[0], far_corner[1], far_corner[2], far_corner[3]],
+        [
+            far_distance[0],
+            far_distance[1],
+            far_distance[2],
+            far_distance[3],
+        ],
+    );
 
-        // Multiply by arbitrary value to scale to -1..1
-        ((f0000
-            + f1000
-            + f0100
-            + f1100
-            + f0010
-            + f1010
-            + f0110
-            + f1110
-            + f0001
-            + f1001
-            + f0101
-            + f1101
-            + f0011
-            + f1011
-            + f0111
-            + f1111)
-            * SCALE_FACTOR)
-            .clamp(-1.0, 1.0)
-    }
+    // Multiply by arbitrary value to scale to -1..1
+    ((f0000
+        + f1000
+        + f0100
+        + f1100
+        + f0010
+        + f1010
+        + f0110
+        + f1110
+        + f0001
+        + f1001
+        + f0101
+        + f1101
+        + f0011
+        + f1011
+        + f0111
+        + f1111)
+        * F::from(SCALE_FACTOR).unwrap())
+    .max(-F::one())
+    .min(F::one())
 }
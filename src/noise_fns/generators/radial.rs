@@ -0,0 +1,79 @@
+use num_traits::Num;
+
+use crate::NoiseFn;
+
+/// Noise function that outputs the distance from a center point.
+///
+/// The output is -1.0 at the center and rises linearly to 1.0 at `radius` units away from it.
+/// Beyond the radius, the output is clamped to 1.0. A radius of zero or less outputs -1.0 only
+/// exactly at the center.
+#[derive(Clone, Copy, Debug)]
+pub struct Radial<const N: usize> {
+    /// Center of the radial ramp. Default is the origin.
+    pub center: [f64; N],
+
+    /// Distance from the center at which the output reaches 1.0. Default is 1.0.
+    pub radius: f64,
+}
+
+impl<const N: usize> Radial<N> {
+    pub const DEFAULT_RADIUS: f64 = 1.0;
+
+    pub fn new() -> Self {
+        Self {
+            center: [0.0; N],
+            radius: Self::DEFAULT_RADIUS,
+        }
+    }
+
+    pub fn with_center(self, center: [f64; N]) -> Self {
+        Self { center, ..self }
+    }
+
+    pub fn with_radius(self, radius: f64) -> Self {
+        Self { radius, ..self }
+    }
+}
+
+impl<const N: usize> Default for Radial<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E, const N: usize> NoiseFn<[E; N]> for Radial<N>
+where
+    E: Num + Copy + Into<f64>,
+{
+    fn get(&self, point: [E; N]) -> f64 {
+        let dist_from_center = point
+            .iter()
+            .zip(&self.center)
+            .map(|(&v, c)| (v.into() - c).powi(2))
+            .sum::<f64>()
+            .sqrt();
+
+        if self.radius <= 0.0 {
+            return if dist_from_center == 0.0 { -1.0 } else { 1.0 };
+        }
+
+        (dist_from_center / self.radius * 2.0 - 1.0).min(1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rises_with_distance() {
+        let radial = Radial::new().with_center([1.0, 1.0]).with_radius(2.0);
+        assert_eq!(radial.get([1.0, 1.0]), -1.0);
+        assert_eq!(radial.get([1.0, 2.0]), 0.0);
+        assert_eq!(radial.get([4.0, 5.0]), 1.0);
+
+        let point = radial.with_radius(0.0);
+        assert_eq!(point.get([1.0, 1.0]), -1.0);
+        assert_eq!(point.get([1.0, 1.5]), 1.0);
+    }
+}
@@ -0,0 +1,74 @@
+use num_traits::Num;
+
+use crate::NoiseFn;
+
+/// Noise function that outputs concentric spheres.
+///
+/// This noise function outputs concentric spheres centered on the origin like
+/// the concentric rings of an onion. The spheres are spaced one unit apart,
+/// divided by the frequency. A frequency of zero outputs 1.0 everywhere, as if
+/// every point were on the sphere at the origin.
+#[derive(Clone, Copy, Debug)]
+pub struct Spheres {
+    /// Frequency of the concentric spheres.
+    pub frequency: f64,
+}
+
+impl Spheres {
+    pub const DEFAULT_FREQUENCY: f64 = 1.0;
+
+    pub fn new() -> Self {
+        Self {
+            frequency: Self::DEFAULT_FREQUENCY,
+        }
+    }
+
+    pub fn with_frequency(self, frequency: f64) -> Self {
+        Self { frequency }
+    }
+}
+
+impl Default for Spheres {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E, const N: usize> NoiseFn<[E; N]> for Spheres
+where
+    E: Num + Copy + Into<f64>,
+{
+    fn get(&self, point: [E; N]) -> f64 {
+        // Scale the inputs by the frequency and calculate the distance of the
+        // point from the origin.
+        let dist_from_center = point
+            .iter()
+            .map(|&v| (v.into() * self.frequency).powi(2))
+            .sum::<f64>()
+            .sqrt();
+
+        let dist_from_smaller_sphere = dist_from_center - dist_from_center.floor();
+        let dist_from_larger_sphere = 1.0 - dist_from_smaller_sphere;
+        let nearest_dist = dist_from_smaller_sphere.min(dist_from_larger_sphere);
+
+        // Shift the result to be in the -1.0 to +1.0 range.
+        1.0 - (nearest_dist * 4.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peaks_on_spheres() {
+        let spheres = Spheres::new().with_frequency(0.5);
+        assert_eq!(spheres.get([0.0, 0.0, 0.0]), 1.0);
+        assert_eq!(spheres.get([1.0, 0.0, 0.0]), -1.0);
+        assert_eq!(spheres.get([0.0, 2.0, 0.0]), 1.0);
+        assert_eq!(spheres.get([0.0, 0.0, 0.5]), 0.0);
+
+        let flat = spheres.with_frequency(0.0);
+        assert_eq!(flat.get([3.7, -1.2, 0.4]), 1.0);
+    }
+}
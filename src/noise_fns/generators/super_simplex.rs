@@ -1,7 +1,9 @@
 use crate::{
+    generators::filter_lattice_noise,
     gradient, math,
-    noise_fns::{NoiseFn, Seedable},
+    noise_fns::{FilteredNoiseFn, NoiseFn, Seedable},
     permutationtable::{NoiseHasher, PermutationTable},
+    SamplePoint,
 };
 use std::ops::Add;
 
@@ -112,6 +114,16 @@ impl Default for SuperSimplex {
     }
 }
 
+impl<const N: usize> FilteredNoiseFn<[f64; N]> for SuperSimplex
+where
+    Self: NoiseFn<[f64; N]>,
+    [f64; N]: SamplePoint,
+{
+    fn get_filtered(&self, point: [f64; N], filter_width: f64) -> f64 {
+        filter_lattice_noise(self, point, filter_width)
+    }
+}
+
 impl Seedable for SuperSimplex {
     /// Sets the seed value for Super Simplex noise
     fn with_seed(self, seed: u32) -> Self {
@@ -174,6 +186,16 @@ impl NoiseFn<[f64; 2]> for SuperSimplex {
     }
 }
 
+/// 2-dimensional Super Simplex noise, evaluated in single precision
+///
+/// The kernel is still computed in double precision, so this is no faster than the `f64`
+/// implementation. It exists so that this function can be used in single-precision pipelines.
+impl NoiseFn<[f32; 2], f32> for SuperSimplex {
+    fn get(&self, point: [f32; 2]) -> f32 {
+        NoiseFn::<[f64; 2]>::get(self, math::cast2(point)) as f32
+    }
+}
+
 /// 3-dimensional Super Simplex noise
 impl NoiseFn<[f64; 3]> for SuperSimplex {
     fn get(&self, point: [f64; 3]) -> f64 {
@@ -250,3 +272,13 @@ impl NoiseFn<[f64; 3]> for SuperSimplex {
         value * NORM_CONSTANT_3D
     }
 }
+
+/// 3-dimensional Super Simplex noise, evaluated in single precision
+///
+/// The kernel is still computed in double precision, so this is no faster than the `f64`
+/// implementation. It exists so that this function can be used in single-precision pipelines.
+impl NoiseFn<[f32; 3], f32> for SuperSimplex {
+    fn get(&self, point: [f32; 3]) -> f32 {
+        NoiseFn::<[f64; 3]>::get(self, math::cast3(point)) as f32
+    }
+}
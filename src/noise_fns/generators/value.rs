@@ -1,15 +1,27 @@
-use crate::math::s_curve::quintic::Quintic;
 use crate::{
     math::{self, interpolate},
-    noise_fns::{NoiseFn, Seedable},
+    noise_fns::{
+        generators::{filter_lattice_noise, CubicValue, Fade, QuinticFade},
+        FilteredNoiseFn, NoiseFn, Seedable,
+    },
     permutationtable::{NoiseHasher, PermutationTable},
+    SamplePoint,
 };
+use std::marker::PhantomData;
 
 /// Noise function that outputs 2/3/4-dimensional Value noise.
+///
+/// The curve used to fade between lattice points is selected with the `S` type parameter, and
+/// defaults to `QuinticFade`. Use `with_fade` to choose a different one.
+///
+/// For cubic interpolation through the four nearest lattice points along each axis, which makes
+/// the first derivative continuous across lattice cells, use [`CubicValue`] or call
+/// `with_cubic_interpolation`.
 #[derive(Clone, Copy, Debug)]
-pub struct Value {
+pub struct Value<S = QuinticFade> {
     seed: u32,
     perm_table: PermutationTable,
+    fade: PhantomData<S>,
 }
 
 impl Value {
@@ -19,17 +31,46 @@ impl Value {
         Self {
             seed: Self::DEFAULT_SEED,
             perm_table: PermutationTable::new(Self::DEFAULT_SEED),
+            fade: PhantomData,
         }
     }
 }
 
+impl<S> Value<S> {
+    /// Returns this noise function modified to use the given curve to fade between lattice
+    /// points.
+    pub fn with_fade<NewS: Fade>(self) -> Value<NewS> {
+        Value {
+            seed: self.seed,
+            perm_table: self.perm_table,
+            fade: PhantomData,
+        }
+    }
+
+    /// Returns a [`CubicValue`] noise function with the same seed, which interpolates through the
+    /// four nearest lattice points along each axis instead of fading between two.
+    pub fn with_cubic_interpolation(self) -> CubicValue {
+        CubicValue::new().with_seed(self.seed)
+    }
+}
+
 impl Default for Value {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl Seedable for Value {
+impl<S: Fade, const N: usize> FilteredNoiseFn<[f64; N]> for Value<S>
+where
+    Self: NoiseFn<[f64; N]>,
+    [f64; N]: SamplePoint,
+{
+    fn get_filtered(&self, point: [f64; N], filter_width: f64) -> f64 {
+        filter_lattice_noise(self, point, filter_width)
+    }
+}
+
+impl<S> Seedable for Value<S> {
     /// Sets the seed value for Value noise
     fn with_seed(self, seed: u32) -> Self {
         // If the new seed is the same as the current seed, just return self.
@@ -41,6 +82,7 @@ impl Seedable for Value {
         Self {
             seed,
             perm_table: PermutationTable::new(seed),
+            fade: PhantomData,
         }
     }
 
@@ -50,7 +92,7 @@ impl Seedable for Value {
 }
 
 /// 2-dimensional value noise
-impl NoiseFn<[f64; 2]> for Value {
+impl<S: Fade> NoiseFn<[f64; 2]> for Value<S> {
     fn get(&self, point: [f64; 2]) -> f64 {
         fn get(perm_table: &PermutationTable, corner: [isize; 2]) -> f64 {
             perm_table.hash(&corner) as f64 / 255.0
@@ -59,7 +101,7 @@ impl NoiseFn<[f64; 2]> for Value {
         let floored = math::map2(point, f64::floor);
         let near_corner = math::to_isize2(floored);
         let far_corner = math::add2(near_corner, math::one2());
-        let weight = math::sub2(point, floored).map_quintic();
+        let weight = math::map2(math::sub2(point, floored), S::fade);
 
         let f00 = get(&self.perm_table, [near_corner[0], near_corner[1]]);
         let f10 = get(&self.perm_table, [far_corner[0], near_corner[1]]);
@@ -75,7 +117,7 @@ impl NoiseFn<[f64; 2]> for Value {
 }
 
 /// 3-dimensional value noise
-impl NoiseFn<[f64; 3]> for Value {
+impl<S: Fade> NoiseFn<[f64; 3]> for Value<S> {
     fn get(&self, point: [f64; 3]) -> f64 {
         fn get(perm_table: &PermutationTable, corner: [isize; 3]) -> f64 {
             perm_table.hash(&corner) as f64 / 255.0
@@ -84,7 +126,7 @@ impl NoiseFn<[f64; 3]> for Value {
         let floored = math::map3(point, f64::floor);
         let near_corner = math::to_isize3(floored);
         let far_corner = math::add3(near_corner, math::one3());
-        let weight = math::sub3(point, floored).map_quintic();
+        let weight = math::map3(math::sub3(point, floored), S::fade);
 
         let f000 = get(
             &self.perm_table,
@@ -132,7 +174,7 @@ impl NoiseFn<[f64; 3]> for Value {
 }
 
 /// 4-dimensional value noise
-impl NoiseFn<[f64; 4]> for Value {
+impl<S: Fade> NoiseFn<[f64; 4]> for Value<S> {
     fn get(&self, point: [f64; 4]) -> f64 {
         fn get(perm_table: &PermutationTable, corner: [isize; 4]) -> f64 {
             perm_table.hash(&corner) as f64 / 255.0
@@ -141,7 +183,7 @@ impl NoiseFn<[f64; 4]> for Value {
         let floored = math::map4(point, f64::floor);
         let near_corner = math::to_isize4(floored);
         let far_corner = math::add4(near_corner, math::one4());
-        let weight = math::sub4(point, floored).map_quintic();
+        let weight = math::map4(math::sub4(point, floored), S::fade);
 
         let f0000 = get(
             &self.perm_table,
@@ -0,0 +1,95 @@
+use num_traits::Num;
+
+use crate::NoiseFn;
+
+macro_rules! wave {
+    ($(#[$attr:meta])* $vis:vis $name:ident($wave_fn:expr)) => {
+        $(#[$attr])*
+        ///
+        /// The wave travels along `direction`, and the length of `direction` is the frequency of
+        /// the wave. Points on planes perpendicular to `direction` produce the same value.
+        #[derive(Clone, Copy, Debug)]
+        $vis struct $name<const N: usize> {
+            /// Direction the wave travels along. Default is one unit along the x axis.
+            pub direction: [f64; N],
+
+            /// Offset of the wave, measured in periods. Default is 0.0.
+            pub phase: f64,
+        }
+
+        impl<const N: usize> $name<N> {
+            pub fn new() -> Self {
+                let mut direction = [0.0; N];
+                if let Some(x) = direction.first_mut() {
+                    *x = 1.0;
+                }
+
+                Self {
+                    direction,
+                    phase: 0.0,
+                }
+            }
+
+            with!(pub direction: [f64; N]);
+            with!(pub phase: f64);
+        }
+
+        impl<const N: usize> Default for $name<N> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<E, const N: usize> NoiseFn<[E; N]> for $name<N>
+        where
+            E: Num + Copy + Into<f64>,
+        {
+            fn get(&self, point: [E; N]) -> f64 {
+                // Project the point onto the direction to find how many periods along the wave
+                // it is.
+                let t = point
+                    .iter()
+                    .zip(&self.direction)
+                    .map(|(&v, d)| v.into() * d)
+                    .sum::<f64>()
+                    + self.phase;
+
+                $wave_fn(t)
+            }
+        }
+    };
+}
+
+wave! {
+    /// Noise function that outputs a sine wave.
+    pub Sine(|t: f64| (t * std::f64::consts::PI * 2.0).sin())
+}
+
+wave! {
+    /// Noise function that outputs a triangle wave, in phase with `Sine`.
+    pub Triangle(|t: f64| 1.0 - 4.0 * ((t + 0.25).rem_euclid(1.0) - 0.5).abs())
+}
+
+wave! {
+    /// Noise function that outputs a sawtooth wave, rising from -1.0 to 1.0 over each period
+    /// and crossing zero where `Sine` does.
+    pub Sawtooth(|t: f64| (t + 0.5).rem_euclid(1.0) * 2.0 - 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn waves_share_phase() {
+        let sine = Sine::new();
+        let triangle = Triangle::new();
+        let sawtooth = Sawtooth::new();
+        for (t, expected) in [(0.0, 0.0), (0.25, 1.0), (0.75, -1.0), (1.0, 0.0)] {
+            assert!((sine.get([t, 5.0]) - expected).abs() < 1e-12);
+            assert!((triangle.get([t, 5.0]) - expected).abs() < 1e-12);
+        }
+        assert_eq!(sawtooth.get([0.0, 5.0]), 0.0);
+        assert_eq!(sawtooth.get([0.25, 5.0]), 0.5);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{
+    noise_fns::{NoiseFn, Seedable},
+    permutationtable::{NoiseHasher, PermutationTable},
+};
+
+/// Noise function that outputs a pseudo-random value for every point.
+///
+/// The raw bits of each coordinate are hashed, so any change to the input point, however small,
+/// produces an unrelated output value. The same point always produces the same value for a given
+/// seed. This is useful for dithering and for randomizing decisions made per sample.
+///
+/// Output values are evenly distributed between -1.0 and 1.0, and only 256 distinct values are
+/// produced.
+#[derive(Clone, Copy, Debug)]
+pub struct WhiteNoise {
+    seed: u32,
+    perm_table: PermutationTable,
+}
+
+impl WhiteNoise {
+    pub const DEFAULT_SEED: u32 = 0;
+
+    pub fn new(seed: u32) -> Self {
+        Self {
+            seed,
+            perm_table: PermutationTable::new(seed),
+        }
+    }
+}
+
+impl Default for WhiteNoise {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+impl Seedable for WhiteNoise {
+    /// Sets the seed value for white noise
+    fn with_seed(self, seed: u32) -> Self {
+        // If the new seed is the same as the current seed, just return self.
+        if self.seed == seed {
+            return self;
+        }
+
+        // Otherwise, regenerate the permutation table based on the new seed.
+        Self {
+            seed,
+            perm_table: PermutationTable::new(seed),
+        }
+    }
+
+    fn seed(&self) -> u32 {
+        self.seed
+    }
+}
+
+/// Hashes the bits of each coordinate one byte at a time, feeding the hash of the previous
+/// coordinates into the next one.
+#[inline]
+fn hash_bits<I>(hasher: &dyn NoiseHasher, coordinate_bits: I) -> usize
+where
+    I: Iterator<Item = u64>,
+{
+    let mut hash = 0;
+    for bits in coordinate_bits {
+        let mut to_hash = [hash as isize; 9];
+        for (byte, slot) in to_hash[1..].iter_mut().enumerate() {
+            *slot = (bits >> (byte * 8)) as isize;
+        }
+        hash = hasher.hash(&to_hash);
+    }
+    hash
+}
+
+impl<const N: usize> NoiseFn<[f64; N]> for WhiteNoise {
+    fn get(&self, point: [f64; N]) -> f64 {
+        let hash = hash_bits(&self.perm_table, point.iter().map(|v| v.to_bits()));
+
+        hash as f64 / 255.0 * 2.0 - 1.0
+    }
+}
+
+impl<const N: usize> NoiseFn<[f32; N], f32> for WhiteNoise {
+    fn get(&self, point: [f32; N]) -> f32 {
+        let hash = hash_bits(
+            &self.perm_table,
+            point.iter().map(|v| u64::from(v.to_bits())),
+        );
+
+        hash as f32 / 255.0 * 2.0 - 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WhiteNoise;
+    use crate::NoiseFn;
+
+    #[test]
+    fn deterministic_and_uncorrelated() {
+        let noise = WhiteNoise::new(5);
+        let values: Vec<f64> = (0..256)
+            .map(|i| noise.get([i as f64 * 1e-9, 0.5]))
+            .collect();
+        let again: Vec<f64> = (0..256)
+            .map(|i| noise.get([i as f64 * 1e-9, 0.5]))
+            .collect();
+        assert_eq!(values, again);
+
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        assert!(mean.abs() < 0.2);
+        assert!(values.iter().any(|&v| v > 0.5) && values.iter().any(|&v| v < -0.5));
+    }
+}
@@ -3,12 +3,14 @@ use crate::{
     noise_fns::{NoiseFn, Seedable},
     permutationtable::{NoiseHasher, PermutationTable},
 };
+use std::rc::Rc;
 
 /// Noise function that outputs Worley noise.
+#[derive(Clone)]
 pub struct Worley {
     /// Specifies the distance function to use when calculating the boundaries of
-    /// the cell.
-    pub distance_function: Box<dyn Fn(&[f64], &[f64]) -> f64>,
+    /// the cell. It is shared between clones.
+    pub distance_function: Rc<dyn Fn(&[f64], &[f64]) -> f64>,
 
     /// Signifies whether the distance from the borders of the cell should be returned, or the
     /// value for the cell.
@@ -29,7 +31,7 @@ impl Worley {
         Self {
             perm_table: PermutationTable::new(seed),
             seed,
-            distance_function: Box::new(distance_functions::euclidean),
+            distance_function: Rc::new(distance_functions::euclidean),
             return_type: ReturnType::Value,
             frequency: Self::DEFAULT_FREQUENCY,
         }
@@ -41,7 +43,7 @@ impl Worley {
         F: Fn(&[f64], &[f64]) -> f64 + 'static,
     {
         Self {
-            distance_function: Box::new(function),
+            distance_function: Rc::new(function),
             ..self
         }
     }
@@ -147,7 +149,7 @@ impl NoiseFn<[f64; 2]> for Worley {
     fn get(&self, point: [f64; 2]) -> f64 {
         worley_2d(
             &self.perm_table,
-            &self.distance_function,
+            &*self.distance_function,
             self.return_type,
             math::mul2(point, self.frequency),
         )
@@ -241,7 +243,7 @@ impl NoiseFn<[f64; 3]> for Worley {
     fn get(&self, point: [f64; 3]) -> f64 {
         worley_3d(
             &self.perm_table,
-            &self.distance_function,
+            &*self.distance_function,
             self.return_type,
             math::mul3(point, self.frequency),
         )
@@ -367,7 +369,7 @@ impl NoiseFn<[f64; 4]> for Worley {
     fn get(&self, point: [f64; 4]) -> f64 {
         worley_4d(
             &self.perm_table,
-            &self.distance_function,
+            &*self.distance_function,
             self.return_type,
             math::mul4(point, self.frequency),
         )
@@ -1,9 +1,20 @@
-pub use self::{abs::*, clamp::*, curve::*, exponent::*, negate::*, scale_bias::*, terrace::*};
+pub use self::{
+    abs::*, auto_normalize::*, bias_gain::*, clamp::*, curve::*, exponent::*, map::*, negate::*,
+    quantize::*, remap::*, scale_bias::*, sigmoid::*, smooth_abs::*, smoothstep::*, terrace::*,
+};
 
 mod abs;
+mod auto_normalize;
+mod bias_gain;
 mod clamp;
 mod curve;
 mod exponent;
+mod map;
 mod negate;
+mod quantize;
+mod remap;
 mod scale_bias;
+mod sigmoid;
+mod smooth_abs;
+mod smoothstep;
 mod terrace;
@@ -1,4 +1,5 @@
 use crate::{NoiseFn, SamplePoint};
+use num_traits::Float;
 
 /// Noise function that outputs the absolute value of the output value from the
 /// source function.
@@ -13,12 +14,13 @@ impl<Source> Abs<Source> {
     }
 }
 
-impl<P, Source> NoiseFn<P> for Abs<Source>
+impl<P, O, Source> NoiseFn<P, O> for Abs<Source>
 where
     P: SamplePoint,
-    Source: NoiseFn<P>,
+    O: Float,
+    Source: NoiseFn<P, O>,
 {
-    fn get(&self, point: P) -> f64 {
+    fn get(&self, point: P) -> O {
         (self.source.get(point)).abs()
     }
 }
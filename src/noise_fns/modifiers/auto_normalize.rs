@@ -0,0 +1,83 @@
+use crate::{utils::RangeEstimator, NoiseFn, SamplePoint};
+
+/// Noise function that maps the output range of the source function onto [-1, 1], using a range
+/// measured by sampling the source.
+///
+/// The range is estimated once, when the wrapper is created, with a `RangeEstimator`. Since it is
+/// an estimate, some outputs may fall slightly outside of [-1, 1]. Combine with `Clamp` if a
+/// strict range is required.
+#[derive(Clone, Debug)]
+pub struct AutoNormalize<Source> {
+    /// Outputs a value.
+    pub source: Source,
+
+    /// The range of the source function which is mapped onto [-1, 1].
+    pub range: (f64, f64),
+}
+
+impl<Source> AutoNormalize<Source> {
+    /// Measures the range of `source` with `estimator`.
+    pub fn new<const N: usize>(source: Source, estimator: &RangeEstimator<N>) -> Self
+    where
+        Source: NoiseFn<[f64; N]>,
+    {
+        let stats = estimator.estimate(&source);
+        Self::with_range(source, stats.min, stats.max)
+    }
+
+    /// Uses a known range instead of measuring it.
+    pub fn with_range(source: Source, min: f64, max: f64) -> Self {
+        Self {
+            source,
+            range: (min, max),
+        }
+    }
+}
+
+impl<P, Source> NoiseFn<P> for AutoNormalize<Source>
+where
+    P: SamplePoint,
+    Source: NoiseFn<P>,
+{
+    fn get(&self, point: P) -> f64 {
+        let (min, max) = self.range;
+        if max > min {
+            (self.source.get(point) - min) / (max - min) * 2.0 - 1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Perlin};
+
+    #[test]
+    fn estimated_range_maps_samples_into_unit_range() {
+        let estimator = RangeEstimator::<2>::new()
+            .with_bounds([(-4.0, 4.0); 2])
+            .with_samples(4096);
+        let perlin = Perlin::new(3);
+        let normalized = AutoNormalize::new(perlin, &estimator);
+        let (min, max) = normalized.range;
+        assert!(min < max);
+
+        // Sampling the same points again gives values within the estimated range, which are
+        // mapped onto [-1, 1] with both ends reached.
+        let stats = estimator.estimate(&perlin);
+        assert!(min <= stats.min && stats.max <= max);
+        let stats = estimator.estimate(&normalized);
+        assert!((stats.min + 1.0).abs() < 1e-12);
+        assert!((stats.max - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn constant_source_maps_to_zero() {
+        let estimator = RangeEstimator::<2>::new().with_samples(64);
+        let normalized = AutoNormalize::new(Constant::new(0.75), &estimator);
+        assert_eq!(normalized.range, (0.75, 0.75));
+        assert_eq!(normalized.get([1.5, -2.0]), 0.0);
+    }
+}
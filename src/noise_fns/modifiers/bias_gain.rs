@@ -0,0 +1,186 @@
+use crate::{math::scale_shift, NoiseFn, SamplePoint};
+use num_traits::Float;
+
+/// Perlin's bias function, which bends values from 0.0 to 1.0 toward 0.0 or
+/// 1.0, keeping the ends fixed and mapping 0.5 onto `bias`.
+fn bias<T: Float>(value: T, bias: T) -> T {
+    let half = T::from(0.5).unwrap();
+    value.powf(bias.ln() / half.ln())
+}
+
+/// Perlin's gain function, which flattens or steepens values from 0.0 to 1.0
+/// around 0.5, keeping 0.0, 0.5 and 1.0 fixed.
+fn gain<T: Float>(value: T, gain: T) -> T {
+    let one = T::one();
+    let two = one + one;
+    if value < one / two {
+        bias(two * value, one - gain) / two
+    } else {
+        one - bias(two - two * value, one - gain) / two
+    }
+}
+
+/// Converts the parameter of `bias` or `gain` to `T` and clamps it to just
+/// inside (0, 1). At 0.0 or 1.0 the bias exponent becomes infinite or zero,
+/// which no longer keeps the ends fixed. The clamp is done after the
+/// conversion, since a limit just inside the range in `f64` may round onto it
+/// in a narrower type.
+fn clamp_parameter<T: Float>(parameter: f64) -> T {
+    let parameter = T::from(parameter).unwrap();
+    parameter.max(T::epsilon()).min(T::one() - T::epsilon())
+}
+
+/// Applies a function on values from 0.0 to 1.0 to a value from -1.0 to 1.0.
+fn apply_normalized<T: Float>(value: T, function: impl Fn(T) -> T) -> T {
+    let two = T::one() + T::one();
+    let value = ((value + T::one()) / two).max(T::zero()).min(T::one());
+    scale_shift(function(value), two)
+}
+
+/// Noise function that applies Perlin's bias function to the output value
+/// from the source function.
+///
+/// The bias bends the output toward -1.0 or 1.0 while keeping both ends fixed.
+/// Because most noise functions will output values that range from -1.0 to
+/// 1.0, this noise function first normalizes the output value (the range
+/// becomes 0.0 to 1.0, clamping values outside of it), applies the bias, then
+/// rescales that value back to the original range. A bias of 0.5 leaves the
+/// value unchanged, smaller values bend it toward -1.0 and larger values bend
+/// it toward 1.0.
+pub struct Bias<Source> {
+    /// Outputs a value.
+    pub source: Source,
+
+    /// Normalized output for a normalized input of 0.5. Ranges from 0.0 to
+    /// 1.0, exclusive; values outside are clamped to just inside this range.
+    /// Default is 0.5.
+    pub bias: f64,
+}
+
+impl<Source> Bias<Source> {
+    pub fn new(source: Source) -> Self {
+        Self { source, bias: 0.5 }
+    }
+
+    pub fn with_bias(self, bias: f64) -> Self {
+        Self { bias, ..self }
+    }
+}
+
+impl<P, O, Source> NoiseFn<P, O> for Bias<Source>
+where
+    P: SamplePoint,
+    O: Float,
+    Source: NoiseFn<P, O>,
+{
+    fn get(&self, point: P) -> O {
+        let amount = clamp_parameter::<O>(self.bias);
+        apply_normalized(self.source.get(point), |value| bias(value, amount))
+    }
+}
+
+/// Noise function that applies Perlin's gain function to the output value
+/// from the source function.
+///
+/// The gain pushes the output toward or away from 0.0 while keeping -1.0, 0.0
+/// and 1.0 fixed. Like [`Bias`], this noise function works on the normalized
+/// output value. A gain of 0.5 leaves the value unchanged, larger values
+/// push it away from 0.0 toward -1.0 or 1.0, making the transition through 0.0
+/// steeper, and smaller values pull it toward 0.0.
+pub struct Gain<Source> {
+    /// Outputs a value.
+    pub source: Source,
+
+    /// Amount of gain. Ranges from 0.0 to 1.0, exclusive; values outside are
+    /// clamped to just inside this range. Default is 0.5.
+    pub gain: f64,
+}
+
+impl<Source> Gain<Source> {
+    pub fn new(source: Source) -> Self {
+        Self { source, gain: 0.5 }
+    }
+
+    pub fn with_gain(self, gain: f64) -> Self {
+        Self { gain, ..self }
+    }
+}
+
+impl<P, O, Source> NoiseFn<P, O> for Gain<Source>
+where
+    P: SamplePoint,
+    O: Float,
+    Source: NoiseFn<P, O>,
+{
+    fn get(&self, point: P) -> O {
+        let amount = clamp_parameter::<O>(self.gain);
+        apply_normalized(self.source.get(point), |value| gain(value, amount))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generators::{Constant, Perlin},
+        modifiers::Map,
+    };
+
+    #[test]
+    fn bias_and_gain_keep_fixed_points() {
+        let point = [0.0, 0.0];
+        for value in [-1.0, 1.0] {
+            assert_eq!(
+                Bias::new(Constant::new(value)).with_bias(0.8).get(point),
+                value
+            );
+            assert_eq!(
+                Gain::new(Constant::new(value)).with_gain(0.8).get(point),
+                value
+            );
+        }
+        assert!((Bias::new(Constant::new(0.0)).with_bias(0.8).get(point) - 0.6).abs() < 1e-12);
+        assert_eq!(Gain::new(Constant::new(0.0)).with_gain(0.8).get(point), 0.0);
+        assert!((Gain::new(Constant::new(-0.5)).with_gain(0.5).get(point) + 0.5).abs() < 1e-12);
+        assert!(Gain::new(Constant::new(-0.5)).with_gain(0.8).get(point) < -0.5);
+    }
+
+    #[test]
+    fn parameters_are_clamped_inside_unit_range() {
+        let point = [0.0, 0.0];
+        for parameter in [-1.0, 0.0, 1.0, 2.0] {
+            for value in [-1.0, -0.5, 0.0, 0.5, 1.0] {
+                let biased = Bias::new(Constant::new(value)).with_bias(parameter);
+                let gained = Gain::new(Constant::new(value)).with_gain(parameter);
+                for output in [biased.get(point), gained.get(point)] {
+                    assert!((-1.0..=1.0).contains(&output));
+                }
+            }
+            // The ends stay fixed even at the limits.
+            for value in [-1.0, 1.0] {
+                let biased = Bias::new(Constant::new(value)).with_bias(parameter);
+                let gained = Gain::new(Constant::new(value)).with_gain(parameter);
+                assert_eq!(biased.get(point), value);
+                assert_eq!(gained.get(point), value);
+            }
+        }
+    }
+
+    #[test]
+    fn single_precision_parameters_are_clamped_inside_unit_range() {
+        let point = [0.0f32, 0.0];
+        for parameter in [-1.0, 0.0, 1.0, 2.0] {
+            for value in [-1.0f32, -0.5, 0.0, 0.5, 1.0] {
+                let constant = || Map::new(Perlin::new(0), move |_: f32| value);
+                let biased = Bias::new(constant()).with_bias(parameter);
+                let gained = Gain::new(constant()).with_gain(parameter);
+                for output in [biased.get(point), gained.get(point)] {
+                    assert!((-1.0..=1.0).contains(&output));
+                    if value.abs() == 1.0 {
+                        assert_eq!(output, value);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{NoiseFn, SamplePoint};
+use num_traits::Float;
 
 /// Noise function that clamps the output value from the source function to a
 /// range of values.
@@ -40,14 +41,19 @@ impl<Source> Clamp<Source> {
     }
 }
 
-impl<P, Source> NoiseFn<P> for Clamp<Source>
+impl<P, O, Source> NoiseFn<P, O> for Clamp<Source>
 where
     P: SamplePoint,
-    Source: NoiseFn<P>,
+    O: Float,
+    Source: NoiseFn<P, O>,
 {
-    fn get(&self, point: P) -> f64 {
+    fn get(&self, point: P) -> O {
         let value = self.source.get(point);
 
-        value.clamp(self.bounds.0, self.bounds.1)
+        num_traits::clamp(
+            value,
+            O::from(self.bounds.0).unwrap(),
+            O::from(self.bounds.1).unwrap(),
+        )
     }
 }
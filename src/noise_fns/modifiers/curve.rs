@@ -1,4 +1,6 @@
 use crate::{math::interpolate, NoiseFn, SamplePoint};
+use num_traits::Float;
+use std::{error, fmt};
 
 /// Noise function that maps the output value from the source function onto an
 /// arbitrary function curve.
@@ -6,20 +8,33 @@ use crate::{math::interpolate, NoiseFn, SamplePoint};
 /// This noise function maps the output value from the source function onto an
 /// application-defined curve. The curve is defined by a number of _control
 /// points_; each control point has an _input value_ that maps to an _output
-/// value_.
+/// value_. Between control points the curve follows the chosen
+/// [`CurveInterpolation`], and outside of them it stays at the output value of
+/// the nearest control point.
 ///
-/// To add control points to the curve, use the `add_control_point` method.
+/// To add control points to the curve, use the `add_control_point` method, or
+/// create the curve with `try_new`, which checks the control points.
 ///
-/// Since the curve is a cubic spline, an application must have a minimum of
-/// four control points to the curve. If there is less than four control
-/// points, the get() method panics. Each control point can have any input
+/// Each interpolation mode needs a minimum number of control points, see
+/// [`CurveInterpolation::min_control_points`]. With fewer control points, the
+/// curve outputs the output value of its only control point, or passes the
+/// source value through if it has none. Each control point can have any input
 /// and output value, although no two control points can have the same input.
 pub struct Curve<Source> {
     /// Outputs a value.
     pub source: Source,
 
+    /// Interpolation between control points. Default is `Cubic`, which matches earlier versions.
+    pub interpolation: CurveInterpolation,
+
     /// Vec that stores the control points.
     control_points: Vec<ControlPoint<f64>>,
+
+    /// Tangent of the monotone cubic spline at each control point.
+    tangents: Vec<f64>,
+
+    /// Tangent of the Catmull-Rom spline at each control point.
+    catmull_rom_tangents: Vec<f64>,
 }
 
 struct ControlPoint<T> {
@@ -27,11 +42,134 @@ struct ControlPoint<T> {
     output: T,
 }
 
+/// Interpolation between the control points of a [`Curve`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CurveInterpolation {
+    /// Outputs the output value of the nearest control point below the source value.
+    Step,
+    /// Straight lines between control points.
+    Linear,
+    /// Cubic spline which never overshoots the output values of neighbouring
+    /// control points, using the Fritsch–Carlson method. A monotone sequence
+    /// of control points gives a monotone curve.
+    MonotoneCubic,
+    /// Catmull-Rom cubic spline. The tangent at each control point is the
+    /// slope between its two neighbours, taking their input values into
+    /// account, so unevenly spaced control points are handled correctly.
+    /// This is smooth, but can overshoot between control points.
+    CatmullRom,
+    /// The cubic interpolation used by libnoise and by earlier versions of
+    /// this curve. It only looks at the output values of the four nearest
+    /// control points, as if they were evenly spaced, so it is not smooth
+    /// where they are not, and it can overshoot between control points.
+    #[default]
+    Cubic,
+}
+
+impl CurveInterpolation {
+    /// Returns the number of control points this interpolation mode needs.
+    pub fn min_control_points(self) -> usize {
+        match self {
+            Self::Step => 1,
+            Self::Linear | Self::MonotoneCubic | Self::CatmullRom | Self::Cubic => 2,
+        }
+    }
+}
+
+/// Error returned when creating a [`Curve`] from invalid control points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CurveError {
+    /// The interpolation mode needs more control points than were given.
+    TooFewControlPoints { required: usize, found: usize },
+    /// Two control points have the same input value.
+    DuplicateInput(f64),
+    /// A control point has an input or output value that is NaN or infinite.
+    NonFiniteControlPoint,
+}
+
+impl fmt::Display for CurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooFewControlPoints { required, found } => write!(
+                f,
+                "curve needs at least {} control points, but {} were given",
+                required, found
+            ),
+            Self::DuplicateInput(input) => {
+                write!(f, "two control points have the same input value {}", input)
+            }
+            Self::NonFiniteControlPoint => write!(f, "control point is not finite"),
+        }
+    }
+}
+
+impl error::Error for CurveError {}
+
 impl<Source> Curve<Source> {
     pub fn new(source: Source) -> Self {
         Self {
             source,
+            interpolation: CurveInterpolation::default(),
             control_points: Vec::with_capacity(4),
+            tangents: Vec::with_capacity(4),
+            catmull_rom_tangents: Vec::with_capacity(4),
+        }
+    }
+
+    /// Creates a curve through the given `(input, output)` control points.
+    ///
+    /// Returns an error if there are too few control points for the
+    /// interpolation mode, if two of them have the same input value, or if
+    /// any of them is not finite.
+    pub fn try_new(
+        source: Source,
+        interpolation: CurveInterpolation,
+        control_points: impl IntoIterator<Item = (f64, f64)>,
+    ) -> Result<Self, CurveError> {
+        let mut control_points: Vec<_> = control_points
+            .into_iter()
+            .map(|(input, output)| ControlPoint { input, output })
+            .collect();
+
+        if control_points
+            .iter()
+            .any(|x| !x.input.is_finite() || !x.output.is_finite())
+        {
+            return Err(CurveError::NonFiniteControlPoint);
+        }
+
+        let required = interpolation.min_control_points();
+        if control_points.len() < required {
+            return Err(CurveError::TooFewControlPoints {
+                required,
+                found: control_points.len(),
+            });
+        }
+
+        control_points.sort_by(|a, b| a.input.total_cmp(&b.input));
+        if let Some(pair) = control_points
+            .windows(2)
+            .find(|pair| (pair[1].input - pair[0].input).abs() < f64::EPSILON)
+        {
+            return Err(CurveError::DuplicateInput(pair[0].input));
+        }
+
+        let mut curve = Self {
+            source,
+            interpolation,
+            control_points,
+            tangents: Vec::new(),
+            catmull_rom_tangents: Vec::new(),
+        };
+        curve.update_tangents();
+
+        Ok(curve)
+    }
+
+    pub fn with_interpolation(self, interpolation: CurveInterpolation) -> Self {
+        Self {
+            interpolation,
+            ..self
         }
     }
 
@@ -58,23 +196,87 @@ impl<Source> Curve<Source> {
                     output: output_value,
                 },
             );
+            self.update_tangents();
         }
 
         self
     }
+
+    /// Recomputes the tangents of the Catmull-Rom spline, and those of the
+    /// monotone cubic spline with the Fritsch–Carlson method.
+    fn update_tangents(&mut self) {
+        let points = &self.control_points;
+        let count = points.len();
+        self.tangents.clear();
+        self.catmull_rom_tangents.clear();
+        if count < 2 {
+            self.tangents.resize(count, 0.0);
+            self.catmull_rom_tangents.resize(count, 0.0);
+            return;
+        }
+
+        let secants: Vec<f64> = points
+            .windows(2)
+            .map(|pair| (pair[1].output - pair[0].output) / (pair[1].input - pair[0].input))
+            .collect();
+
+        // The slope between the neighbours of each control point, or the
+        // secant to the only neighbour at the ends.
+        self.catmull_rom_tangents.push(secants[0]);
+        for triple in points.windows(3) {
+            self.catmull_rom_tangents
+                .push((triple[2].output - triple[0].output) / (triple[2].input - triple[0].input));
+        }
+        self.catmull_rom_tangents.push(secants[count - 2]);
+
+        // Start from the average of the neighbouring secants, with flat
+        // tangents at local extrema.
+        self.tangents.push(secants[0]);
+        for pair in secants.windows(2) {
+            self.tangents.push(if pair[0] * pair[1] <= 0.0 {
+                0.0
+            } else {
+                (pair[0] + pair[1]) * 0.5
+            });
+        }
+        self.tangents.push(secants[count - 2]);
+
+        // Limit the tangents of each segment so that it cannot overshoot.
+        for (index, &secant) in secants.iter().enumerate() {
+            if secant == 0.0 {
+                self.tangents[index] = 0.0;
+                self.tangents[index + 1] = 0.0;
+                continue;
+            }
+
+            let alpha = self.tangents[index] / secant;
+            let beta = self.tangents[index + 1] / secant;
+            let magnitude = alpha.hypot(beta);
+            if magnitude > 3.0 {
+                let tau = 3.0 / magnitude;
+                self.tangents[index] = tau * alpha * secant;
+                self.tangents[index + 1] = tau * beta * secant;
+            }
+        }
+    }
 }
 
-impl<P, Source> NoiseFn<P> for Curve<Source>
+impl<P, O, Source> NoiseFn<P, O> for Curve<Source>
 where
     P: SamplePoint,
-    Source: NoiseFn<P>,
+    O: Float,
+    Source: NoiseFn<P, O>,
 {
-    fn get(&self, point: P) -> f64 {
-        // confirm that there's at least 4 control points in the vector.
-        assert!(self.control_points.len() >= 4);
-
-        // get output value from the source function
+    fn get(&self, point: P) -> O {
         let source_value = self.source.get(point);
+        let count = self.control_points.len();
+        if count == 0 {
+            return source_value;
+        }
+
+        // The control points are stored in double precision, so the curve is
+        // evaluated in double precision too.
+        let source_value = source_value.to_f64().unwrap();
 
         // Find the first element in the control point array that has a input
         // value larger than the output value from the source function
@@ -82,45 +284,159 @@ where
             .control_points
             .iter()
             .position(|x| x.input > source_value)
-            .unwrap_or_else(|| self.control_points.len());
-
-        // if index_pos < 2 {
-        //     println!(
-        //         "index_pos in curve was less than 2! source value was {}",
-        //         source_value
-        //     );
-        // }
-
-        // ensure that the index is at least 2 and less than control_points.len()
-        let index_pos = index_pos.clamp(2, self.control_points.len());
-
-        // Find the four nearest control points so that we can perform cubic
-        // interpolation.
-        let index0 = (index_pos - 2).clamp(0, self.control_points.len() - 1);
-        let index1 = (index_pos - 1).clamp(0, self.control_points.len() - 1);
-        let index2 = index_pos.clamp(0, self.control_points.len() - 1);
-        let index3 = (index_pos + 1).clamp(0, self.control_points.len() - 1);
-
-        // If some control points are missing (which occurs if the value from
-        // the source function is greater than the largest input value or less
-        // than the smallest input value of the control point array), get the
-        // corresponding output value of the nearest control point and exit.
-        if index1 == index2 {
-            return self.control_points[index1].output;
-        }
-
-        // Compute the alpha value used for cubic interpolation
-        let input0 = self.control_points[index1].input;
-        let input1 = self.control_points[index2].input;
-        let alpha = (source_value - input0) / (input1 - input0);
-
-        // Now perform the cubic interpolation and return.
-        interpolate::cubic(
-            self.control_points[index0].output,
-            self.control_points[index1].output,
-            self.control_points[index2].output,
-            self.control_points[index3].output,
-            alpha,
+            .unwrap_or(count);
+
+        // If the value from the source function is outside the range of the
+        // control points, get the output value of the nearest control point
+        // and exit.
+        if index_pos == 0 {
+            return O::from(self.control_points[0].output).unwrap();
+        }
+        if index_pos == count {
+            return O::from(self.control_points[count - 1].output).unwrap();
+        }
+
+        let index1 = index_pos - 1;
+        let index2 = index_pos;
+        let point1 = &self.control_points[index1];
+        let point2 = &self.control_points[index2];
+        let width = point2.input - point1.input;
+        let alpha = (source_value - point1.input) / width;
+
+        let value = match self.interpolation {
+            CurveInterpolation::Step => point1.output,
+            CurveInterpolation::Linear => interpolate::linear(point1.output, point2.output, alpha),
+            CurveInterpolation::MonotoneCubic => hermite(
+                point1.output,
+                point2.output,
+                width * self.tangents[index1],
+                width * self.tangents[index2],
+                alpha,
+            ),
+            CurveInterpolation::CatmullRom => hermite(
+                point1.output,
+                point2.output,
+                width * self.catmull_rom_tangents[index1],
+                width * self.catmull_rom_tangents[index2],
+                alpha,
+            ),
+            CurveInterpolation::Cubic => {
+                // Find the four nearest control points, repeating the end
+                // points where they are missing.
+                let index0 = index1.saturating_sub(1);
+                let index3 = (index2 + 1).min(count - 1);
+
+                interpolate::cubic(
+                    self.control_points[index0].output,
+                    point1.output,
+                    point2.output,
+                    self.control_points[index3].output,
+                    alpha,
+                )
+            }
+        };
+
+        O::from(value).unwrap()
+    }
+}
+
+/// Cubic Hermite interpolation between `start` and `end`, with the tangents
+/// given per unit of `alpha`.
+fn hermite(start: f64, end: f64, start_tangent: f64, end_tangent: f64, alpha: f64) -> f64 {
+    let alpha2 = alpha * alpha;
+    let alpha3 = alpha2 * alpha;
+    (2.0 * alpha3 - 3.0 * alpha2 + 1.0) * start
+        + (alpha3 - 2.0 * alpha2 + alpha) * start_tangent
+        + (3.0 * alpha2 - 2.0 * alpha3) * end
+        + (alpha3 - alpha2) * end_tangent
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Constant;
+
+    fn curve(interpolation: CurveInterpolation, value: f64) -> Curve<Constant> {
+        Curve::try_new(
+            Constant::new(value),
+            interpolation,
+            [(1.0, 1.0), (-1.0, -1.0), (0.0, 0.0), (0.1, 0.9)],
         )
+        .unwrap()
+    }
+
+    #[test]
+    fn try_new_checks_control_points() {
+        assert_eq!(
+            Curve::try_new(Constant::new(0.0), CurveInterpolation::Linear, [(0.0, 0.0)]).err(),
+            Some(CurveError::TooFewControlPoints {
+                required: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            Curve::try_new(
+                Constant::new(0.0),
+                CurveInterpolation::Linear,
+                [(0.0, 0.0), (0.0, 1.0)]
+            )
+            .err(),
+            Some(CurveError::DuplicateInput(0.0))
+        );
+        assert!(Curve::try_new(Constant::new(0.0), CurveInterpolation::Step, [(0.0, 0.0)]).is_ok());
+    }
+
+    #[test]
+    fn interpolation_modes() {
+        use CurveInterpolation::*;
+        let point = [0.0, 0.0];
+
+        assert_eq!(curve(Step, 0.05).get(point), 0.0);
+        assert_eq!(curve(Linear, 0.05).get(point), 0.45);
+        assert_eq!(curve(Linear, -2.0).get(point), -1.0);
+        assert_eq!(curve(Linear, 2.0).get(point), 1.0);
+
+        // The Catmull-Rom and legacy cubic splines overshoot the range of
+        // the control points, but the monotone cubic spline does not.
+        let sample = |interpolation| {
+            (0..=200).map(move |step| curve(interpolation, step as f64 / 100.0 - 1.0).get(point))
+        };
+        assert!(sample(CatmullRom).any(|value| !(-1.0..=1.0).contains(&value)));
+        assert!(sample(Cubic).any(|value| !(-1.0..=1.0).contains(&value)));
+        assert!(sample(MonotoneCubic).all(|value| (-1.0..=1.0).contains(&value)));
+        assert_eq!(curve(MonotoneCubic, 0.1).get(point), 0.9);
+    }
+
+    #[test]
+    fn catmull_rom_follows_uneven_spacing() {
+        let point = [0.0, 0.0];
+        let line = |interpolation, value| {
+            Curve::try_new(
+                Constant::new(value),
+                interpolation,
+                [(0.0, 0.0), (1.0, 1.0), (3.0, 3.0), (4.0, 4.0)],
+            )
+            .unwrap()
+            .get(point)
+        };
+        // Control points on a line give the same line, however they are spaced.
+        for value in [0.25, 1.5, 2.0, 3.5] {
+            assert!((line(CurveInterpolation::CatmullRom, value) - value).abs() < 1e-12);
+        }
+        // The legacy cubic assumes even spacing, so it bends away from the line.
+        assert!((line(CurveInterpolation::Cubic, 1.5) - 1.5).abs() > 0.01);
+        // The tangents between neighbours are (3 - 0) / (3 - 0) = 1 and (4 - 1) / (4 - 1) = 1.
+        assert_eq!(line(CurveInterpolation::CatmullRom, 3.0), 3.0);
+    }
+
+    #[test]
+    fn few_control_points_do_not_panic() {
+        let point = [0.0, 0.0];
+        let curve = Curve::new(Constant::new(0.5));
+        assert_eq!(curve.get(point), 0.5);
+        let curve = curve.add_control_point(0.0, 0.25);
+        assert_eq!(curve.get(point), 0.25);
+        let curve = curve.add_control_point(1.0, 0.75);
+        assert_eq!(curve.get(point), 0.5);
     }
 }
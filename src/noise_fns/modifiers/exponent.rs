@@ -1,4 +1,5 @@
 use crate::{math::scale_shift, NoiseFn, SamplePoint};
+use num_traits::Float;
 
 /// Noise function that maps the output value from the source function onto an
 /// exponential curve.
@@ -29,16 +30,18 @@ impl<Source> Exponent<Source> {
     }
 }
 
-impl<P, Source> NoiseFn<P> for Exponent<Source>
+impl<P, O, Source> NoiseFn<P, O> for Exponent<Source>
 where
     P: SamplePoint,
-    Source: NoiseFn<P>,
+    O: Float,
+    Source: NoiseFn<P, O>,
 {
-    fn get(&self, point: P) -> f64 {
+    fn get(&self, point: P) -> O {
+        let two = O::one() + O::one();
         let mut value = self.source.get(point);
-        value = (value + 1.0) / 2.0;
+        value = (value + O::one()) / two;
         value = value.abs();
-        value = value.powf(self.exponent);
-        scale_shift(value, 2.0)
+        value = value.powf(O::from(self.exponent).unwrap());
+        scale_shift(value, two)
     }
 }
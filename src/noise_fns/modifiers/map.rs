@@ -0,0 +1,101 @@
+use crate::{NoiseFn, SamplePoint};
+
+/// Noise function that applies a closure to the output value from the source function.
+///
+/// This allows one-off transfer functions without writing a new noise function type:
+///
+/// ```rust
+/// use noise::{generators::Perlin, modifiers::Map, NoiseFn};
+///
+/// let gamma = Map::new(Perlin::default(), |value: f64| value.abs().powf(2.2));
+/// let val = gamma.get([42.4, 37.7]);
+/// ```
+pub struct Map<Source, F> {
+    /// Outputs a value.
+    pub source: Source,
+
+    /// Function applied to the output value.
+    pub function: F,
+}
+
+impl<Source, F> Map<Source, F> {
+    pub fn new(source: Source, function: F) -> Self {
+        Self { source, function }
+    }
+}
+
+impl<P, O, Source, F> NoiseFn<P, O> for Map<Source, F>
+where
+    P: SamplePoint,
+    Source: NoiseFn<P, O>,
+    F: Fn(O) -> O,
+{
+    fn get(&self, point: P) -> O {
+        (self.function)(self.source.get(point))
+    }
+}
+
+/// Noise function that applies a closure to the input point and the output value from the
+/// source function.
+///
+/// Useful for transfer functions that vary over space, such as fading the output out with
+/// distance from the origin.
+pub struct MapWithPoint<Source, F> {
+    /// Outputs a value.
+    pub source: Source,
+
+    /// Function applied to the input point and the output value.
+    pub function: F,
+}
+
+impl<Source, F> MapWithPoint<Source, F> {
+    pub fn new(source: Source, function: F) -> Self {
+        Self { source, function }
+    }
+}
+
+impl<P, O, Source, F> NoiseFn<P, O> for MapWithPoint<Source, F>
+where
+    P: SamplePoint + Clone,
+    Source: NoiseFn<P, O>,
+    F: Fn(P, O) -> O,
+{
+    fn get(&self, point: P) -> O {
+        let value = self.source.get(point.clone());
+        (self.function)(point, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Perlin};
+
+    #[test]
+    fn map_applies_function_to_output() {
+        let doubled = Map::new(Constant::new(0.25), |value: f64| value * 2.0);
+        assert_eq!(doubled.get([3.0, -1.0]), 0.5);
+
+        let perlin = Perlin::new(2);
+        let squared = Map::new(perlin, |value: f32| value * value);
+        let point = [0.3f32, -1.7];
+        let value: f32 = perlin.get(point);
+        assert_eq!(squared.get(point), value * value);
+    }
+
+    #[test]
+    fn map_with_point_sees_sampled_point() {
+        let perlin = Perlin::new(2);
+        let faded = MapWithPoint::new(perlin, |point: [f64; 2], value: f64| {
+            value / (1.0 + point[0] * point[0] + point[1] * point[1])
+        });
+        for point in [[0.0, 0.0], [0.3, -1.7], [12.25, 3.5]] {
+            let expected = perlin.get(point) / (1.0 + point[0] * point[0] + point[1] * point[1]);
+            assert_eq!(faded.get(point), expected);
+        }
+        let offset = MapWithPoint::new(Constant::new(1.0), |point: [f64; 3], value: f64| {
+            value + point[2]
+        });
+        assert_eq!(offset.get([0.0, 0.0, -4.0]), -3.0);
+    }
+}
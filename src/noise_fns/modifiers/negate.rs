@@ -1,4 +1,5 @@
 use crate::{NoiseFn, SamplePoint};
+use std::ops::Neg;
 
 /// Noise function that negates the output value from the source function.
 pub struct Negate<Source> {
@@ -12,12 +13,13 @@ impl<Source> Negate<Source> {
     }
 }
 
-impl<P, Source> NoiseFn<P> for Negate<Source>
+impl<P, O, Source> NoiseFn<P, O> for Negate<Source>
 where
     P: SamplePoint,
-    Source: NoiseFn<P>,
+    O: Neg<Output = O>,
+    Source: NoiseFn<P, O>,
 {
-    fn get(&self, point: P) -> f64 {
+    fn get(&self, point: P) -> O {
         -self.source.get(point)
     }
 }
@@ -0,0 +1,255 @@
+use crate::{
+    permutationtable::{NoiseHasher, PermutationTable},
+    NoiseFn, SamplePoint, Seedable,
+};
+use num_traits::{Float, ToPrimitive};
+
+/// Dithering applied by [`Quantize`] before snapping values to levels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Dither {
+    /// Rounds each value to the nearest level.
+    #[default]
+    None,
+    /// Ordered dithering with a 4x4 Bayer matrix over the first two
+    /// coordinates of the point. Gives a regular pattern.
+    Bayer,
+    /// Dithering with a white noise threshold per cell of the point, chosen by
+    /// the seed. Gives an irregular pattern.
+    Noise,
+}
+
+const BAYER_MATRIX: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Noise function that snaps the output value from the source function to a
+/// set of discrete levels.
+///
+/// The levels are either a number of evenly spaced values between two bounds,
+/// set with `with_levels`, or all multiples of a step size, set with
+/// `with_step`. Default is 16 levels from -1.0 to 1.0.
+///
+/// Plain rounding turns smooth gradients into visible bands. Dithering breaks
+/// these up by varying the rounding threshold from cell to cell, so that the
+/// average output follows the source value. The threshold of each cell is
+/// derived from the sample point, so the pattern stays fixed in place when
+/// the sampled area moves.
+pub struct Quantize<Source> {
+    /// Outputs a value.
+    pub source: Source,
+
+    /// Distance between two levels.
+    step: f64,
+
+    /// Value of the lowest level, or any level if there are no bounds.
+    origin: f64,
+
+    /// Lowest and highest level, if the number of levels is limited.
+    bounds: Option<(f64, f64)>,
+
+    /// Dithering to apply. Default is none.
+    pub dither: Dither,
+
+    /// Number of dither cells per unit of the sample point coordinates. This
+    /// should match the spacing of the samples, so that each sample falls into
+    /// its own cell. Default is 1.0.
+    pub dither_frequency: f64,
+
+    seed: u32,
+    perm_table: PermutationTable,
+}
+
+impl<Source> Quantize<Source> {
+    pub const DEFAULT_SEED: u32 = 0;
+    pub const DEFAULT_LEVELS: usize = 16;
+    pub const DEFAULT_DITHER_FREQUENCY: f64 = 1.0;
+
+    pub fn new(source: Source) -> Self {
+        Self {
+            source,
+            step: 0.0,
+            origin: 0.0,
+            bounds: None,
+            dither: Dither::default(),
+            dither_frequency: Self::DEFAULT_DITHER_FREQUENCY,
+            seed: Self::DEFAULT_SEED,
+            perm_table: PermutationTable::new(Self::DEFAULT_SEED),
+        }
+        .with_levels(Self::DEFAULT_LEVELS, -1.0, 1.0)
+    }
+
+    /// Snaps to `count` evenly spaced levels from `min` to `max`, both
+    /// included. Values outside of this range are snapped to the nearest
+    /// bound. A single level outputs `min` everywhere. If `min` is greater
+    /// than `max`, the two are swapped.
+    ///
+    /// # Panics
+    /// Panics if `count` is zero.
+    pub fn with_levels(self, count: usize, min: f64, max: f64) -> Self {
+        assert!(count > 0);
+        if count == 1 {
+            return Self {
+                step: 0.0,
+                origin: min,
+                bounds: Some((min, min)),
+                ..self
+            };
+        }
+
+        // A negative step would flip the meaning of the dither threshold.
+        let (min, max) = if min > max { (max, min) } else { (min, max) };
+        Self {
+            step: (max - min) / (count - 1) as f64,
+            origin: min,
+            bounds: Some((min, max)),
+            ..self
+        }
+    }
+
+    /// Snaps to all multiples of `step`, without bounds.
+    ///
+    /// # Panics
+    /// Panics if `step` is not greater than zero.
+    pub fn with_step(self, step: f64) -> Self {
+        assert!(step > 0.0);
+
+        Self {
+            step,
+            origin: 0.0,
+            bounds: None,
+            ..self
+        }
+    }
+
+    pub fn with_dither(self, dither: Dither) -> Self {
+        Self { dither, ..self }
+    }
+
+    pub fn with_dither_frequency(self, dither_frequency: f64) -> Self {
+        Self {
+            dither_frequency,
+            ..self
+        }
+    }
+
+    /// Returns the rounding threshold at the given point, from 0.0 to 1.0.
+    fn threshold<E: ToPrimitive>(&self, point: &[E]) -> f64 {
+        let cell = |x: &E| (x.to_f64().unwrap() * self.dither_frequency).floor() as isize;
+
+        match self.dither {
+            Dither::None => 0.5,
+            Dither::Bayer => {
+                let x = point.first().map_or(0, |x| cell(x).rem_euclid(4)) as usize;
+                let y = point.get(1).map_or(0, |y| cell(y).rem_euclid(4)) as usize;
+                (f64::from(BAYER_MATRIX[y][x]) + 0.5) / 16.0
+            }
+            Dither::Noise => {
+                // Chain the hash through each coordinate, so that points of any
+                // dimension can be hashed without allocating.
+                let hash = point
+                    .iter()
+                    .fold(0, |hash, x| self.perm_table.hash(&[hash as isize, cell(x)]));
+                (hash as f64 + 0.5) / 256.0
+            }
+        }
+    }
+}
+
+impl<P, O, Source> NoiseFn<P, O> for Quantize<Source>
+where
+    P: SamplePoint + AsRef<[P::Scalar]>,
+    P::Scalar: ToPrimitive,
+    O: Float,
+    Source: NoiseFn<P, O>,
+{
+    fn get(&self, point: P) -> O {
+        if self.step == 0.0 {
+            return O::from(self.origin).unwrap();
+        }
+        let threshold = self.threshold(point.as_ref());
+        let value = self.source.get(point).to_f64().unwrap();
+
+        let level = ((value - self.origin) / self.step + threshold).floor();
+        let value = self.origin + level * self.step;
+
+        O::from(match self.bounds {
+            Some((min, max)) => value.clamp(min, max),
+            None => value,
+        })
+        .unwrap()
+    }
+}
+
+impl<Source> Seedable for Quantize<Source> {
+    fn with_seed(self, seed: u32) -> Self {
+        // If the new seed is the same as the current seed, just return self.
+        if self.seed == seed {
+            return self;
+        }
+
+        // Otherwise, regenerate the permutation table based on the new seed.
+        Self {
+            seed,
+            perm_table: PermutationTable::new(seed),
+            ..self
+        }
+    }
+
+    fn seed(&self) -> u32 {
+        self.seed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Perlin};
+
+    #[test]
+    fn snaps_to_levels() {
+        let quantize = |value| Quantize::new(Constant::new(value)).with_levels(5, -1.0, 1.0);
+        assert_eq!(quantize(0.2).get([0.0, 0.0]), 0.0);
+        assert_eq!(quantize(0.3).get([0.0, 0.0]), 0.5);
+        assert_eq!(quantize(-2.0).get([0.0, 0.0]), -1.0);
+
+        let quantize = Quantize::new(Constant::new(7.4)).with_step(2.0);
+        assert_eq!(quantize.get([0.0, 0.0]), 8.0);
+
+        let single = Quantize::new(Constant::new(0.7)).with_levels(1, 0.25, 1.0);
+        assert_eq!(single.get([0.0, 0.0]), 0.25);
+
+        let reversed = Quantize::new(Constant::new(0.3)).with_levels(5, 1.0, -1.0);
+        assert_eq!(reversed.get([0.0, 0.0]), 0.5);
+    }
+
+    #[test]
+    fn works_with_single_precision() {
+        let perlin = Perlin::new(4);
+        let quantize = Quantize::new(perlin)
+            .with_levels(5, -1.0, 1.0)
+            .with_dither(Dither::Noise);
+        for x in 0..16 {
+            let point = [x as f32 * 0.37, 1.5, -0.25];
+            let double = [point[0] as f64, 1.5, -0.25];
+            let single: f32 = quantize.get(point);
+            assert_eq!(single as f64, quantize.get(double));
+        }
+    }
+
+    #[test]
+    fn dithering_preserves_average() {
+        for dither in [Dither::Bayer, Dither::Noise] {
+            let quantize = Quantize::new(Constant::new(0.3))
+                .with_step(1.0)
+                .with_dither(dither);
+            let mut sum = 0.0;
+            for x in 0..64 {
+                for y in 0..64 {
+                    let value = quantize.get([x as f64, y as f64]);
+                    assert!(value == 0.0 || value == 1.0);
+                    assert_eq!(value, quantize.get([x as f64 + 0.5, y as f64 + 0.5]));
+                    sum += value;
+                }
+            }
+            assert!((sum / 4096.0 - 0.3).abs() < 0.05);
+        }
+    }
+}
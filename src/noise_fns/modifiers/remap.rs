@@ -0,0 +1,114 @@
+use crate::{NoiseFn, SamplePoint};
+use num_traits::Float;
+
+/// Noise function that linearly maps the output value from the source function
+/// from an input range onto an output range.
+///
+/// The input range is mapped onto the output range so that `in_min` outputs
+/// `out_min` and `in_max` outputs `out_max`. Values outside of the input range
+/// are extrapolated, unless clamping is enabled. Either range may be reversed.
+/// If both input bounds are equal, values below them output `out_min` and all
+/// other values output `out_max`.
+pub struct Remap<Source> {
+    /// Outputs a value.
+    pub source: Source,
+
+    /// Range of the output value from the source function. Default is -1.0 to
+    /// 1.0.
+    pub input_bounds: (f64, f64),
+
+    /// Range the input range is mapped onto. Default is 0.0 to 1.0.
+    pub output_bounds: (f64, f64),
+
+    /// Clamps the output value to the output range. Default is false.
+    pub clamp: bool,
+}
+
+impl<Source> Remap<Source> {
+    pub fn new(source: Source) -> Self {
+        Self {
+            source,
+            input_bounds: (-1.0, 1.0),
+            output_bounds: (0.0, 1.0),
+            clamp: false,
+        }
+    }
+
+    pub fn with_input_bounds(self, in_min: f64, in_max: f64) -> Self {
+        Self {
+            input_bounds: (in_min, in_max),
+            ..self
+        }
+    }
+
+    pub fn with_output_bounds(self, out_min: f64, out_max: f64) -> Self {
+        Self {
+            output_bounds: (out_min, out_max),
+            ..self
+        }
+    }
+
+    pub fn with_clamp(self, clamp: bool) -> Self {
+        Self { clamp, ..self }
+    }
+}
+
+impl<P, O, Source> NoiseFn<P, O> for Remap<Source>
+where
+    P: SamplePoint,
+    O: Float,
+    Source: NoiseFn<P, O>,
+{
+    fn get(&self, point: P) -> O {
+        let (in_min, in_max) = self.input_bounds;
+        let (out_min, out_max) = self.output_bounds;
+
+        let value = self.source.get(point).to_f64().unwrap();
+        if in_min == in_max {
+            return O::from(if value < in_min { out_min } else { out_max }).unwrap();
+        }
+
+        let mut alpha = (value - in_min) / (in_max - in_min);
+        if self.clamp {
+            alpha = alpha.clamp(0.0, 1.0);
+        }
+
+        O::from(out_min + alpha * (out_max - out_min)).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Constant;
+
+    fn remap(value: f64) -> Remap<Constant> {
+        Remap::new(Constant::new(value)).with_output_bounds(10.0, 20.0)
+    }
+
+    #[test]
+    fn maps_and_clamps() {
+        let point = [0.0, 0.0];
+        assert_eq!(remap(-1.0).get(point), 10.0);
+        assert_eq!(remap(0.5).get(point), 17.5);
+        assert_eq!(remap(2.0).get(point), 25.0);
+        assert_eq!(remap(2.0).with_clamp(true).get(point), 20.0);
+        assert_eq!(remap(-3.0).with_clamp(true).get(point), 10.0);
+    }
+
+    #[test]
+    fn handles_reversed_and_equal_ranges() {
+        let point = [0.0, 0.0];
+        let reversed = |value| remap(value).with_input_bounds(1.0, -1.0);
+        assert_eq!(reversed(1.0).get(point), 10.0);
+        assert_eq!(reversed(0.5).get(point), 12.5);
+        assert_eq!(reversed(-2.0).with_clamp(true).get(point), 20.0);
+        let falling = remap(0.5).with_output_bounds(1.0, 0.0);
+        assert_eq!(falling.get(point), 0.25);
+
+        let step = |value| remap(value).with_input_bounds(0.25, 0.25);
+        assert_eq!(step(0.0).get(point), 10.0);
+        assert_eq!(step(0.25).get(point), 20.0);
+        assert_eq!(step(1.0).get(point), 20.0);
+    }
+}
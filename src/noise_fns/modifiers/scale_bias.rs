@@ -1,4 +1,5 @@
 use crate::{NoiseFn, SamplePoint};
+use num_traits::Float;
 
 /// Noise function that applies a scaling factor and a bias to the output value
 /// from the source function.
@@ -36,18 +37,19 @@ impl<Source> ScaleBias<Source> {
     }
 }
 
-impl<P, Source> NoiseFn<P> for ScaleBias<Source>
+impl<P, O, Source> NoiseFn<P, O> for ScaleBias<Source>
 where
     P: SamplePoint,
-    Source: NoiseFn<P>,
+    O: Float,
+    Source: NoiseFn<P, O>,
 {
     #[cfg(not(target_os = "emscripten"))]
-    fn get(&self, point: P) -> f64 {
-        (self.source.get(point)).mul_add(self.scale, self.bias)
+    fn get(&self, point: P) -> O {
+        (self.source.get(point)).mul_add(O::from(self.scale).unwrap(), O::from(self.bias).unwrap())
     }
 
     #[cfg(target_os = "emscripten")]
-    fn get(&self, point: P) -> f64 {
-        (self.source.get(point) * self.scale) + self.bias
+    fn get(&self, point: P) -> O {
+        (self.source.get(point) * O::from(self.scale).unwrap()) + O::from(self.bias).unwrap()
     }
 }
@@ -0,0 +1,82 @@
+use crate::{NoiseFn, SamplePoint};
+use num_traits::Float;
+
+/// Noise function that maps the output value from the source function onto a
+/// logistic curve.
+///
+/// The curve rises from 0.0 to 1.0, passing 0.5 at the center. The steepness
+/// is the slope at the center divided by 0.25, so larger values give a sharper
+/// transition.
+pub struct Sigmoid<Source> {
+    /// Outputs a value.
+    pub source: Source,
+
+    /// Steepness of the curve. Default is 1.0.
+    pub steepness: f64,
+
+    /// Value that is mapped onto 0.5. Default is 0.0.
+    pub center: f64,
+}
+
+impl<Source> Sigmoid<Source> {
+    pub fn new(source: Source) -> Self {
+        Self {
+            source,
+            steepness: 1.0,
+            center: 0.0,
+        }
+    }
+
+    pub fn with_steepness(self, steepness: f64) -> Self {
+        Self { steepness, ..self }
+    }
+
+    pub fn with_center(self, center: f64) -> Self {
+        Self { center, ..self }
+    }
+}
+
+impl<P, O, Source> NoiseFn<P, O> for Sigmoid<Source>
+where
+    P: SamplePoint,
+    O: Float,
+    Source: NoiseFn<P, O>,
+{
+    fn get(&self, point: P) -> O {
+        let steepness = O::from(self.steepness).unwrap();
+        let center = O::from(self.center).unwrap();
+        let value = self.source.get(point);
+
+        O::one() / (O::one() + (-steepness * (value - center)).exp())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Constant;
+
+    #[test]
+    fn center_maps_to_half() {
+        let point = [0.0, 0.0];
+        assert_eq!(Sigmoid::new(Constant::new(0.0)).get(point), 0.5);
+        let shifted = Sigmoid::new(Constant::new(0.3)).with_center(0.3);
+        assert_eq!(shifted.get(point), 0.5);
+        let value = Sigmoid::new(Constant::new(1.0)).get(point);
+        assert!((value - 1.0 / (1.0 + (-1.0f64).exp())).abs() < 1e-12);
+    }
+
+    #[test]
+    fn steepness_sharpens_transition() {
+        let point = [0.0, 0.0];
+        let sigmoid = |steepness| {
+            Sigmoid::new(Constant::new(0.1))
+                .with_steepness(steepness)
+                .get(point)
+        };
+        assert!(sigmoid(1.0) < sigmoid(10.0));
+        assert!(sigmoid(100.0) > 0.99);
+        // A negative steepness mirrors the curve.
+        assert!((sigmoid(-10.0) - (1.0 - sigmoid(10.0))).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{NoiseFn, SamplePoint};
+use num_traits::Float;
+
+/// Noise function that outputs a smoothed absolute value of the output value from the source
+/// function.
+///
+/// Unlike [`Abs`](super::Abs), the output is rounded off around zero instead of folding into a
+/// crease, computed as `sqrt(value² + smoothness²)`. A value of zero therefore outputs
+/// `smoothness`, and far from zero the output approaches the absolute value.
+pub struct SmoothAbs<Source> {
+    /// Outputs a value.
+    pub source: Source,
+
+    /// Width of the rounded region around zero. A smoothness of 0.0 is the same as `Abs`. Default
+    /// is 0.1.
+    pub smoothness: f64,
+}
+
+impl<Source> SmoothAbs<Source> {
+    pub const DEFAULT_SMOOTHNESS: f64 = 0.1;
+
+    pub fn new(source: Source) -> Self {
+        Self {
+            source,
+            smoothness: Self::DEFAULT_SMOOTHNESS,
+        }
+    }
+
+    pub fn with_smoothness(self, smoothness: f64) -> Self {
+        Self { smoothness, ..self }
+    }
+}
+
+impl<P, O, Source> NoiseFn<P, O> for SmoothAbs<Source>
+where
+    P: SamplePoint,
+    O: Float,
+    Source: NoiseFn<P, O>,
+{
+    fn get(&self, point: P) -> O {
+        let value = self.source.get(point);
+        let smoothness = O::from(self.smoothness.abs()).unwrap();
+
+        (value * value + smoothness * smoothness).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Constant;
+
+    fn smooth_abs(value: f64) -> f64 {
+        SmoothAbs::new(Constant::new(value)).get([0.0, 0.0])
+    }
+
+    #[test]
+    fn approaches_abs_away_from_zero() {
+        for value in [-10.0, -2.5, 2.5, 10.0] {
+            assert!((smooth_abs(value) - f64::abs(value)).abs() < 0.01);
+        }
+        let sharp = SmoothAbs::new(Constant::new(-0.25)).with_smoothness(0.0);
+        assert_eq!(sharp.get([0.0, 0.0]), 0.25);
+    }
+
+    #[test]
+    fn is_smooth_and_non_zero_at_zero() {
+        assert_eq!(smooth_abs(0.0), SmoothAbs::<Constant>::DEFAULT_SMOOTHNESS);
+        // The output is symmetric, so the slope is zero at the origin instead of jumping.
+        const H: f64 = 1e-4;
+        assert_eq!(smooth_abs(H), smooth_abs(-H));
+        let slope = (smooth_abs(2.0 * H) - smooth_abs(H)) / H;
+        assert!(slope.abs() < 0.01);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{NoiseFn, SamplePoint};
+use num_traits::Float;
+
+macro_rules! smoothstep {
+    ($(#[$doc:meta])* $vis:vis $name:ident($curve:expr)) => {
+        $(#[$doc])*
+        ///
+        /// Outputs 0.0 at or below the first edge and 1.0 at or above the
+        /// second edge. If the first edge is larger than the second, the
+        /// curve falls instead. If both edges are equal, the curve is a step
+        /// from 0.0 below them to 1.0 at and above them.
+        $vis struct $name<Source> {
+            /// Outputs a value.
+            pub source: Source,
+
+            /// Values at which the curve starts and stops rising. Default is
+            /// -1.0 to 1.0.
+            pub edges: (f64, f64),
+        }
+
+        impl<Source> $name<Source> {
+            pub fn new(source: Source) -> Self {
+                Self {
+                    source,
+                    edges: (-1.0, 1.0),
+                }
+            }
+
+            pub fn with_edges(self, edge0: f64, edge1: f64) -> Self {
+                Self {
+                    edges: (edge0, edge1),
+                    ..self
+                }
+            }
+        }
+
+        impl<P, O, Source> NoiseFn<P, O> for $name<Source>
+        where
+            P: SamplePoint,
+            O: Float,
+            Source: NoiseFn<P, O>,
+        {
+            fn get(&self, point: P) -> O {
+                let (edge0, edge1) = self.edges;
+                let value = self.source.get(point).to_f64().unwrap();
+                if edge0 == edge1 {
+                    return if value < edge0 { O::zero() } else { O::one() };
+                }
+
+                let x = ((value - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+
+                O::from($curve(x)).unwrap()
+            }
+        }
+    };
+}
+
+smoothstep! {
+    /// Noise function that maps the output value from the source function
+    /// onto a cubic Hermite curve between two edges, `3x² - 2x³`.
+    pub Smoothstep(|x: f64| x * x * (3.0 - 2.0 * x))
+}
+smoothstep! {
+    /// Noise function that maps the output value from the source function
+    /// onto Perlin's quintic curve between two edges, `6x⁵ - 15x⁴ + 10x³`,
+    /// which also has zero second derivative at the edges.
+    pub Smootherstep(|x: f64| x * x * x * (x * (x * 6.0 - 15.0) + 10.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Constant;
+
+    #[test]
+    fn curves_meet_edges() {
+        let point = [0.0, 0.0];
+        let smoothstep = |value| Smoothstep::new(Constant::new(value)).get(point);
+        let smootherstep = |value| Smootherstep::new(Constant::new(value)).get(point);
+        let curves: [&dyn Fn(f64) -> f64; 2] = [&smoothstep, &smootherstep];
+        for curve in curves {
+            assert_eq!(curve(-2.0), 0.0);
+            assert_eq!(curve(-1.0), 0.0);
+            assert_eq!(curve(0.0), 0.5);
+            assert_eq!(curve(1.0), 1.0);
+            assert_eq!(curve(2.0), 1.0);
+        }
+        assert_eq!(smoothstep(-0.5), 0.15625);
+        assert_eq!(smootherstep(-0.5), 0.103515625);
+    }
+
+    #[test]
+    fn handles_reversed_and_equal_edges() {
+        let point = [0.0, 0.0];
+        let reversed = |value| {
+            Smoothstep::new(Constant::new(value))
+                .with_edges(1.0, -1.0)
+                .get(point)
+        };
+        assert_eq!(reversed(1.0), 0.0);
+        assert_eq!(reversed(-1.0), 1.0);
+        assert_eq!(reversed(0.5), 0.15625);
+
+        let step = |value| {
+            Smootherstep::new(Constant::new(value))
+                .with_edges(0.25, 0.25)
+                .get(point)
+        };
+        assert_eq!(step(0.0), 0.0);
+        assert_eq!(step(0.25), 1.0);
+        assert_eq!(step(1.0), 1.0);
+    }
+}
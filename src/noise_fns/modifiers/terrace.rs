@@ -1,4 +1,9 @@
-use crate::{math::interpolate, NoiseFn, SamplePoint};
+use crate::{
+    math::interpolate,
+    permutationtable::{NoiseHasher, PermutationTable},
+    NoiseFn, SamplePoint, Seedable,
+};
+use num_traits::Float;
 
 /// Noise function that maps the output value from the source function onto a
 /// terrace-forming curve.
@@ -9,13 +14,20 @@ use crate::{math::interpolate, NoiseFn, SamplePoint};
 /// which resets the slope to zero at that point, producing a "terracing"
 /// effect.
 ///
-/// To add control points to the curve, use the `add_control_point` method.
+/// To add control points to the curve, use the `add_control_point` method, or
+/// use `with_steps` for evenly spaced terraces.
 ///
-/// An application must add a minimum of two control points to the curve. If
-/// there are less than two control points, the get() method panics. The
-/// control points can have any value, although no two control points can
-/// have the same value. There is no limit to the number of control points
-/// that can be added to the curve.
+/// The curve needs two or more control points. With fewer, it outputs the
+/// value of its only control point, or passes the source value through if it
+/// has none. The control points can have any value, although no two control
+/// points can have the same value. There is no limit to the number of control
+/// points that can be added to the curve.
+///
+/// The `smoothness` controls how abruptly each terrace rises to the next, and
+/// the `jitter` moves the height of each terrace by a random amount chosen by
+/// the seed, so that the terraces don't look evenly machined. The random amount
+/// depends on the value of the control point rather than its position, so
+/// adding a control point doesn't move the other terraces around.
 ///
 /// The noise function clamps the output value from the source function if that
 /// value is less than the value of the lowest control point or greater than
@@ -31,19 +43,66 @@ pub struct Terrace<Source> {
     /// inverted.
     pub invert_terraces: bool,
 
+    /// Shape of the rise between terraces, from 0.0 to 2.0. 0.0 gives sharp
+    /// steps, 1.0 gives the quadratic terrace-forming curve, and 2.0 a
+    /// straight line without terraces. Values in between blend these shapes,
+    /// and values outside the range are clamped to it. Default is 1.0.
+    pub smoothness: f64,
+
+    /// Amount the height of each terrace is moved by, as a fraction of half
+    /// the distance to its nearest neighbouring terrace. Ranges from 0.0 to
+    /// 1.0, where terraces can touch but never cross. The lowest and highest
+    /// terraces never move beyond the lowest and highest control points.
+    /// Default is 0.0.
+    pub jitter: f64,
+
     /// Vec that stores the control points.
     control_points: Vec<f64>,
+
+    seed: u32,
+    perm_table: PermutationTable,
 }
 
 impl<Source> Terrace<Source> {
+    pub const DEFAULT_SEED: u32 = 0;
+    pub const DEFAULT_SMOOTHNESS: f64 = 1.0;
+    pub const DEFAULT_JITTER: f64 = 0.0;
+
     pub fn new(source: Source) -> Self {
         Terrace {
             source,
             invert_terraces: false,
+            smoothness: Self::DEFAULT_SMOOTHNESS,
+            jitter: Self::DEFAULT_JITTER,
             control_points: Vec::with_capacity(2),
+            seed: Self::DEFAULT_SEED,
+            perm_table: PermutationTable::new(Self::DEFAULT_SEED),
         }
     }
 
+    /// Replaces the control points with `steps` evenly spaced terraces from
+    /// `min` to `max`.
+    pub fn with_steps(self, steps: usize, min: f64, max: f64) -> Self {
+        let steps = steps.max(1);
+        let control_points = (0..=steps)
+            .map(|step| interpolate::linear(min, max, step as f64 / steps as f64))
+            .collect();
+
+        Terrace {
+            control_points,
+            ..self
+        }
+        .sorted()
+    }
+
+    pub fn with_smoothness(self, smoothness: f64) -> Self {
+        Terrace { smoothness, ..self }
+    }
+
+    pub fn with_jitter(self, jitter: f64) -> Self {
+        Terrace { jitter, ..self }
+    }
+
     /// Adds a control point to the terrace-forming curve.
     ///
     /// Two or more control points define the terrace-forming curve. The start
@@ -74,6 +133,44 @@ impl<Source> Terrace<Source> {
         Terrace { ..self }
     }
 
+    /// Sorts the control points in ascending order and removes duplicates.
+    fn sorted(mut self) -> Self {
+        self.control_points.sort_by(f64::total_cmp);
+        self.control_points
+            .dedup_by(|a, b| (*a - *b).abs() < f64::EPSILON);
+        self
+    }
+
+    /// Returns the height of the terrace at the given control point, moved by
+    /// the jitter.
+    fn level(&self, index: usize) -> f64 {
+        let value = self.control_points[index];
+        if self.jitter == 0.0 {
+            return value;
+        }
+
+        let below = index
+            .checked_sub(1)
+            .map(|below| value - self.control_points[below]);
+        let above = self
+            .control_points
+            .get(index + 1)
+            .map(|above| above - value);
+        let gap = match (below, above) {
+            (Some(below), Some(above)) => below.min(above),
+            (gap, None) | (None, gap) => gap.unwrap_or(0.0),
+        };
+
+        // Hash the control value quantized to 1/1024, one byte at a time.
+        let quantized = (value * 1024.0).round() as i64;
+        let bytes = [0, 8, 16, 24].map(|shift| (quantized >> shift) as isize);
+        let random = self.perm_table.hash(&bytes) as f64 / 255.0 * 2.0 - 1.0;
+
+        let first = self.control_points[0];
+        let last = self.control_points[self.control_points.len() - 1];
+        (value + random * self.jitter.clamp(0.0, 1.0) * 0.5 * gap).clamp(first, last)
+    }
+
     /// Enables or disables the inversion of the terrain-forming curve between
     /// the control points.
     pub fn invert_terraces(self, invert_terraces: bool) -> Self {
@@ -84,17 +181,21 @@ impl<Source> Terrace<Source> {
     }
 }
 
-impl<P, Source> NoiseFn<P> for Terrace<Source>
+impl<P, O, Source> NoiseFn<P, O> for Terrace<Source>
 where
     P: SamplePoint,
-    Source: NoiseFn<P>,
+    O: Float,
+    Source: NoiseFn<P, O>,
 {
-    fn get(&self, point: P) -> f64 {
-        // confirm that there's at least 2 control points in the vector.
-        assert!(self.control_points.len() >= 2);
-
-        // get output value from the source function
+    fn get(&self, point: P) -> O {
         let source_value = self.source.get(point);
+        if self.control_points.is_empty() {
+            return source_value;
+        }
+
+        // The control points are stored in double precision, so the curve is
+        // evaluated in double precision too.
+        let source_value = source_value.to_f64().unwrap();
 
         // Find the first element in the control point array that has a input
         // value larger than the output value from the source function
@@ -114,27 +215,108 @@ where
         // than the smallest input value of the control point array), get the
         // corresponding output value of the nearest control point and exit.
         if index0 == index1 {
-            return self.control_points[index1];
+            return O::from(self.level(index1)).unwrap();
         }
 
         // Compute the alpha value used for cubic interpolation
-        let mut input0 = self.control_points[index0];
-        let mut input1 = self.control_points[index1];
+        let input0 = self.control_points[index0];
+        let input1 = self.control_points[index1];
         let mut alpha = (source_value - input0) / (input1 - input0);
+        let mut level0 = self.level(index0);
+        let mut level1 = self.level(index1);
 
         if self.invert_terraces {
             alpha = 1.0 - alpha;
-            std::mem::swap(&mut input0, &mut input1);
+            std::mem::swap(&mut level0, &mut level1);
         }
 
-        // Squaring the alpha produces the terrace effect.
-        alpha *= alpha;
+        // Raising the alpha to a power produces the terrace effect. The
+        // default smoothness squares it, a smoothness of zero leaves a sharp
+        // step and a smoothness of two a straight line. Larger values would
+        // bend the curve the other way and invert the terraces.
+        let smoothness = self.smoothness.clamp(0.0, 2.0);
+        alpha = if smoothness > 0.0 {
+            alpha.powf(2.0 / smoothness)
+        } else {
+            0.0
+        };
 
         // Now perform the cubic interpolation and return.
-        interpolate::linear(input0, input1, alpha)
+        O::from(interpolate::linear(level0, level1, alpha)).unwrap()
+    }
+}
+
+impl<Source> Seedable for Terrace<Source> {
+    fn with_seed(self, seed: u32) -> Self {
+        // If the new seed is the same as the current seed, just return self.
+        if self.seed == seed {
+            return self;
+        }
+
+        // Otherwise, regenerate the permutation table based on the new seed.
+        Terrace {
+            seed,
+            perm_table: PermutationTable::new(seed),
+            ..self
+        }
+    }
+
+    fn seed(&self) -> u32 {
+        self.seed
     }
 }
 
 fn clamp_index(index: isize, min: usize, max: usize) -> usize {
     index.clamp(min as isize, max as isize) as usize
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Constant;
+
+    fn terrace(value: f64) -> Terrace<Constant> {
+        Terrace::new(Constant::new(value)).with_steps(4, -1.0, 1.0)
+    }
+
+    #[test]
+    fn steps_are_evenly_spaced() {
+        let point = [0.0, 0.0];
+        assert_eq!(terrace(-0.5).get(point), -0.5);
+        assert_eq!(terrace(-0.25).get(point), -0.375);
+        assert_eq!(terrace(-0.25).with_smoothness(0.0).get(point), -0.5);
+        assert_eq!(terrace(-0.25).with_smoothness(2.0).get(point), -0.25);
+        assert_eq!(terrace(-0.25).with_smoothness(4.0).get(point), -0.25);
+        assert_eq!(terrace(-0.25).with_smoothness(-1.0).get(point), -0.5);
+        assert_eq!(terrace(2.0).get(point), 1.0);
+        assert_eq!(Terrace::new(Constant::new(0.3)).get(point), 0.3);
+    }
+
+    #[test]
+    fn jitter_keeps_terraces_ordered() {
+        let point = [0.0, 0.0];
+        for seed in 0..16 {
+            let level = |value| terrace(value).with_jitter(1.0).with_seed(seed).get(point);
+            let levels: Vec<f64> = [-1.0, -0.5, 0.0, 0.5, 1.0]
+                .iter()
+                .map(|&v| level(v))
+                .collect();
+            assert!(levels.windows(2).all(|pair| pair[0] <= pair[1]));
+            assert!(levels.iter().all(|level| level.abs() <= 1.0));
+        }
+        assert_ne!(terrace(0.0).with_jitter(1.0).get(point), 0.0);
+    }
+
+    #[test]
+    fn jitter_follows_control_values() {
+        let point = [0.0, 0.0];
+        for seed in 0..16 {
+            let jittered = terrace(0.5).with_jitter(1.0).with_seed(seed);
+            let level = jittered.get(point);
+            // A new terrace below changes the index of the terrace at 0.5, but
+            // not its height. The nearest neighbours stay 0.5 away.
+            let extended = jittered.add_control_point(-2.0);
+            assert_eq!(extended.get(point), level);
+        }
+    }
+}
@@ -1,4 +1,6 @@
-pub use self::{blend::*, select::*};
+pub use self::{biome_select::*, blend::*, multi_select::*, select::*};
 
+mod biome_select;
 mod blend;
+mod multi_select;
 mod select;
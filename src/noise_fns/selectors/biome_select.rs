@@ -0,0 +1,223 @@
+use crate::{NoiseFn, SamplePoint};
+
+/// How [`BiomeSelect`] weights each site by its distance from the control values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SiteWeighting {
+    /// Weights sites by `1 / distance^power`. Higher powers give sharper boundaries.
+    InverseDistance { power: f64 },
+    /// Weights sites by a Gaussian of their distance. Smaller widths give sharper boundaries. The
+    /// width must be positive.
+    Gaussian { width: f64 },
+}
+
+/// Noise function that outputs a weighted blend of source functions, chosen by the output values
+/// from two control functions, such as temperature and moisture.
+///
+/// Each source function is placed at a site in the two-dimensional space of control values. At
+/// each point the two control values are evaluated, and every site is weighted by its distance
+/// from them, so that source functions near the control values dominate and boundaries between
+/// them are smooth. Source functions whose weight is below `min_weight` times the weight of the
+/// nearest site are not evaluated. If that leaves out every source function, for example when
+/// `min_weight` is above 1.0, the source function of the nearest site is used.
+///
+/// ```rust
+/// use noise::{generators::Constant, selectors::BiomeSelect, NoiseFn};
+///
+/// let biomes = BiomeSelect::new(
+///     Constant::new(0.9),  // temperature
+///     Constant::new(-0.8), // moisture
+///     vec![
+///         ([1.0, -1.0], Constant::new(0.1)), // desert
+///         ([1.0, 1.0], Constant::new(0.3)),  // rainforest
+///         ([-1.0, 0.0], Constant::new(0.6)), // tundra
+///     ],
+/// );
+/// let val = biomes.get([0.0, 0.0]);
+/// ```
+pub struct BiomeSelect<T, X, Y> {
+    /// Outputs the first coordinate of the control values.
+    pub control1: X,
+
+    /// Outputs the second coordinate of the control values.
+    pub control2: Y,
+
+    /// Source functions, each with its site in control space. Private, so that it can't be
+    /// emptied.
+    sites: Vec<([f64; 2], T)>,
+
+    /// Weighting of sites by distance. Private, so that it is always checked by
+    /// `with_weighting`.
+    weighting: SiteWeighting,
+
+    /// Weight relative to the nearest site below which a source function is left out. Default is
+    /// 0.001.
+    pub min_weight: f64,
+}
+
+impl<T, X, Y> BiomeSelect<T, X, Y> {
+    pub const DEFAULT_WEIGHTING: SiteWeighting = SiteWeighting::InverseDistance { power: 4.0 };
+    pub const DEFAULT_MIN_WEIGHT: f64 = 0.001;
+
+    pub fn new(control1: X, control2: Y, sites: impl IntoIterator<Item = ([f64; 2], T)>) -> Self {
+        let sites: Vec<_> = sites.into_iter().collect();
+        assert!(!sites.is_empty());
+
+        Self {
+            control1,
+            control2,
+            sites,
+            weighting: Self::DEFAULT_WEIGHTING,
+            min_weight: Self::DEFAULT_MIN_WEIGHT,
+        }
+    }
+
+    /// # Panics
+    /// Panics if `weighting` is Gaussian with a width that is not positive.
+    pub fn with_weighting(self, weighting: SiteWeighting) -> Self {
+        if let SiteWeighting::Gaussian { width } = weighting {
+            assert!(width > 0.0);
+        }
+        Self { weighting, ..self }
+    }
+
+    pub fn with_min_weight(self, min_weight: f64) -> Self {
+        Self { min_weight, ..self }
+    }
+
+    /// Returns the source functions, each with its site in control space, in the order they were
+    /// passed to `new`.
+    pub fn sites(&self) -> &[([f64; 2], T)] {
+        &self.sites
+    }
+
+    /// Returns the weighting of sites by distance. Default is inverse distance with a power of
+    /// 4.0.
+    pub fn weighting(&self) -> SiteWeighting {
+        self.weighting
+    }
+
+    /// Returns the squared distance of the site at `index` from the control values.
+    fn distance_squared(&self, index: usize, control: [f64; 2]) -> f64 {
+        let site = self.sites[index].0;
+        let dx = site[0] - control[0];
+        let dy = site[1] - control[1];
+        dx * dx + dy * dy
+    }
+
+    /// Returns the unnormalized weight of a site, given its squared distance and that of the
+    /// nearest site.
+    fn weight(&self, distance_squared: f64, nearest: f64) -> f64 {
+        match self.weighting {
+            SiteWeighting::InverseDistance { power } => {
+                if nearest == 0.0 {
+                    if distance_squared == 0.0 {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                } else {
+                    distance_squared.powf(-0.5 * power)
+                }
+            }
+            // Gaussian weights are taken relative to the nearest site, so that they cannot all
+            // underflow to zero far away from every site.
+            SiteWeighting::Gaussian { width } => {
+                (-(distance_squared - nearest) / (2.0 * width * width)).exp()
+            }
+        }
+    }
+}
+
+impl<P, T, X, Y> NoiseFn<P> for BiomeSelect<T, X, Y>
+where
+    P: SamplePoint + Clone,
+    T: NoiseFn<P>,
+    X: NoiseFn<P>,
+    Y: NoiseFn<P>,
+{
+    fn get(&self, point: P) -> f64 {
+        let control = [
+            self.control1.get(point.clone()),
+            self.control2.get(point.clone()),
+        ];
+
+        // The nearest site has the largest weight, so the cutoff is known before any weight is
+        // summed.
+        let (nearest_index, nearest) = (0..self.sites.len())
+            .map(|index| (index, self.distance_squared(index, control)))
+            .fold((0, f64::INFINITY), |nearest, site| {
+                if site.1 < nearest.1 {
+                    site
+                } else {
+                    nearest
+                }
+            });
+        let cutoff = self.weight(nearest, nearest) * self.min_weight;
+
+        let mut value = 0.0;
+        let mut kept = 0.0;
+        for (index, (_, source)) in self.sites.iter().enumerate() {
+            let weight = self.weight(self.distance_squared(index, control), nearest);
+            if weight > 0.0 && weight >= cutoff {
+                value += weight * source.get(point.clone());
+                kept += weight;
+            }
+        }
+
+        if kept > 0.0 {
+            value / kept
+        } else {
+            self.sites[nearest_index].1.get(point)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Constant;
+
+    fn biomes(control: [f64; 2]) -> BiomeSelect<Constant, Constant, Constant> {
+        BiomeSelect::new(
+            Constant::new(control[0]),
+            Constant::new(control[1]),
+            vec![
+                ([0.0, 0.0], Constant::new(1.0)),
+                ([1.0, 0.0], Constant::new(2.0)),
+                ([100.0, 100.0], Constant::new(100.0)),
+            ],
+        )
+    }
+
+    #[test]
+    fn blends_nearby_sites() {
+        let point = [0.0, 0.0];
+        for weighting in [
+            SiteWeighting::InverseDistance { power: 4.0 },
+            SiteWeighting::Gaussian { width: 0.25 },
+        ] {
+            assert_eq!(biomes([0.0, 0.0]).with_weighting(weighting).get(point), 1.0);
+            assert_eq!(biomes([0.5, 0.0]).with_weighting(weighting).get(point), 1.5);
+            assert_eq!(biomes([0.5, 3.0]).with_weighting(weighting).get(point), 1.5);
+            let value = biomes([0.25, 0.0]).with_weighting(weighting).get(point);
+            assert!(value > 1.0 && value < 1.5);
+        }
+    }
+
+    #[test]
+    fn falls_back_to_nearest_site() {
+        let point = [0.0, 0.0];
+        // No site is left with a normalized weight above 2.0.
+        assert_eq!(biomes([0.9, 0.0]).with_min_weight(2.0).get(point), 2.0);
+        // With a high power the inverse distance weights of all sites underflow to zero.
+        let far =
+            biomes([200.0, 200.0]).with_weighting(SiteWeighting::InverseDistance { power: 1000.0 });
+        assert_eq!(far.get(point), 100.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_zero_gaussian_width() {
+        biomes([0.0, 0.0]).with_weighting(SiteWeighting::Gaussian { width: 0.0 });
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    math::{interpolate, s_curve::cubic::Cubic},
+    NoiseFn, SamplePoint,
+};
+
+/// Noise function that outputs the value selected from one of any number of source functions,
+/// chosen by comparing the output value from a control function against ordered thresholds.
+///
+/// Each source function is paired with a threshold, and is selected where the control value is
+/// at or above its threshold but below the next one. The source function with the lowest
+/// threshold is also selected below it. At each boundary between two source functions the
+/// output is blended over a range of twice the falloff, so at most two source functions are
+/// evaluated at any point.
+///
+/// ```rust
+/// use noise::{generators::Constant, selectors::MultiSelect, NoiseFn};
+///
+/// let terrain = MultiSelect::new(
+///     Constant::new(0.1),
+///     vec![
+///         (-1.0, Constant::new(-0.5)), // ocean
+///         (0.0, Constant::new(0.1)),   // plains
+///         (0.5, Constant::new(0.8)),   // mountains
+///     ],
+/// );
+/// assert_eq!(terrain.get([0.0, 0.0]), 0.1);
+/// ```
+pub struct MultiSelect<T, X> {
+    /// Determines the source function to select.
+    pub control: X,
+
+    /// Source functions, sorted by ascending threshold. Private, so that `get` can rely on the
+    /// order.
+    sources: Vec<(f64, T)>,
+
+    /// Half the width of the blended range at each boundary. Should be less than half the
+    /// smallest gap between two thresholds. Default is 0.0.
+    pub falloff: f64,
+}
+
+impl<T, X> MultiSelect<T, X> {
+    /// Creates a selector from `(threshold, source)` pairs, given in any order. They are sorted by
+    /// ascending threshold, keeping the given order of sources with equal thresholds.
+    ///
+    /// # Panics
+    /// Panics if `sources` is empty.
+    pub fn new(control: X, sources: impl IntoIterator<Item = (f64, T)>) -> Self {
+        let mut sources: Vec<_> = sources.into_iter().collect();
+        assert!(!sources.is_empty());
+        sources.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        Self {
+            control,
+            sources,
+            falloff: 0.0,
+        }
+    }
+
+    pub fn with_falloff(self, falloff: f64) -> Self {
+        Self { falloff, ..self }
+    }
+
+    /// Returns the thresholds and source functions, sorted by ascending threshold. This is the
+    /// order the selector keeps them in, which can differ from the order they were passed to
+    /// `new` in. They can't be modified in place, since that could break the order; create a new
+    /// selector instead.
+    pub fn sources(&self) -> &[(f64, T)] {
+        &self.sources
+    }
+}
+
+impl<P, T, X> NoiseFn<P> for MultiSelect<T, X>
+where
+    P: SamplePoint + Clone,
+    T: NoiseFn<P>,
+    X: NoiseFn<P>,
+{
+    fn get(&self, point: P) -> f64 {
+        let control_value = self.control.get(point.clone());
+        let index = self
+            .sources
+            .partition_point(|(threshold, _)| *threshold <= control_value)
+            .saturating_sub(1);
+
+        if self.falloff > 0.0 {
+            // Boundary with the next lower source function, and with the next higher one.
+            let lower = (index > 0).then(|| (index - 1, self.sources[index].0));
+            let upper = self
+                .sources
+                .get(index + 1)
+                .map(|(threshold, _)| (index, *threshold));
+
+            let nearest = match (lower, upper) {
+                (Some(lower), Some(upper)) => {
+                    if control_value - lower.1 < upper.1 - control_value {
+                        Some(lower)
+                    } else {
+                        Some(upper)
+                    }
+                }
+                (lower, upper) => lower.or(upper),
+            };
+
+            if let Some((below, threshold)) = nearest {
+                if (control_value - threshold).abs() < self.falloff {
+                    let alpha = ((control_value - threshold + self.falloff) / (2.0 * self.falloff))
+                        .map_cubic();
+
+                    return interpolate::linear(
+                        self.sources[below].1.get(point.clone()),
+                        self.sources[below + 1].1.get(point),
+                        alpha,
+                    );
+                }
+            }
+        }
+
+        self.sources[index].1.get(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Constant;
+
+    #[test]
+    fn selects_and_blends_by_threshold() {
+        let point = [0.0, 0.0];
+        let select = |control| {
+            MultiSelect::new(
+                Constant::new(control),
+                vec![
+                    (0.5, Constant::new(3.0)),
+                    (-0.5, Constant::new(1.0)),
+                    (0.0, Constant::new(2.0)),
+                ],
+            )
+        };
+
+        assert_eq!(select(-2.0).get(point), 1.0);
+        assert_eq!(select(-0.25).get(point), 1.0);
+        assert_eq!(select(0.0).get(point), 2.0);
+        assert_eq!(select(0.25).get(point), 2.0);
+        assert_eq!(select(2.0).get(point), 3.0);
+
+        assert_eq!(select(0.0).with_falloff(0.1).get(point), 1.5);
+        assert_eq!(select(0.5).with_falloff(0.1).get(point), 2.5);
+        assert_eq!(select(0.25).with_falloff(0.1).get(point), 2.0);
+        assert_eq!(select(-0.5).with_falloff(0.1).get(point), 1.0);
+
+        let thresholds: Vec<f64> = select(0.0).sources().iter().map(|(t, _)| *t).collect();
+        assert_eq!(thresholds, vec![-0.5, 0.0, 0.5]);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     fractals::FractalPerlin,
     transforms::{Transformed, UniformScale},
-    NoiseFn, Seedable,
+    FilteredNoiseFn, NoiseFn, Seedable,
 };
 use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
@@ -30,9 +30,11 @@ pub struct Turbulence<Source> {
     pub roughness: usize,
 
     seed: u32,
-    distorters: [Transformed<FractalPerlin, UniformScale<f64>>; 4],
+    distorters: [Distorter; 4],
 }
 
+type Distorter = Transformed<FractalPerlin, UniformScale<f64>>;
+
 impl<Source> Turbulence<Source> {
     pub const DEFAULT_SEED: u32 = 0;
     pub const DEFAULT_FREQUENCY: f64 = 1.0;
@@ -135,20 +137,42 @@ where
     Source: NoiseFn<[f64; 2]>,
 {
     fn get(&self, point: [f64; 2]) -> f64 {
-        // First, create offsets based on the input values to keep the sampled
-        // points from being near a integer boundary. This is a result of
-        // using perlin noise, which returns zero at integer boundaries.
-        let x0 = point[0] + 12414.0 / 65536.0;
-        let y0 = point[1] + 65124.0 / 65536.0;
+        let point = distort_2d(&self.distorters, self.power, point, |d, p| d.get(p));
+        self.source.get(point)
+    }
+}
 
-        let x1 = point[0] + 26519.0 / 65536.0;
-        let y1 = point[1] + 18128.0 / 65536.0;
+/// The distortion is computed with the filtered distorters, but the footprint is not adjusted
+/// for how much the distortion stretches or squeezes the source.
+impl<Source> FilteredNoiseFn<[f64; 2]> for Turbulence<Source>
+where
+    Source: FilteredNoiseFn<[f64; 2]>,
+{
+    fn get_filtered(&self, point: [f64; 2], filter_width: f64) -> f64 {
+        let point = distort_2d(&self.distorters, self.power, point, |d, p| {
+            d.get_filtered(p, filter_width)
+        });
+        self.source.get_filtered(point, filter_width)
+    }
+}
 
-        let x_distort = point[0] + (self.distorters[0].get([x0, y0]) * self.power);
-        let y_distort = point[1] + (self.distorters[1].get([x1, y1]) * self.power);
+fn distort_2d<S>(distorters: &[Distorter; 4], power: f64, point: [f64; 2], sample: S) -> [f64; 2]
+where
+    S: Fn(&Distorter, [f64; 2]) -> f64,
+{
+    // First, create offsets based on the input values to keep the sampled
+    // points from being near a integer boundary. This is a result of
+    // using perlin noise, which returns zero at integer boundaries.
+    let x0 = point[0] + 12414.0 / 65536.0;
+    let y0 = point[1] + 65124.0 / 65536.0;
 
-        self.source.get([x_distort, y_distort])
-    }
+    let x1 = point[0] + 26519.0 / 65536.0;
+    let y1 = point[1] + 18128.0 / 65536.0;
+
+    let x_distort = point[0] + (sample(&distorters[0], [x0, y0]) * power);
+    let y_distort = point[1] + (sample(&distorters[1], [x1, y1]) * power);
+
+    [x_distort, y_distort]
 }
 
 impl<Source> NoiseFn<[f64; 3]> for Turbulence<Source>
@@ -156,63 +180,106 @@ where
     Source: NoiseFn<[f64; 3]>,
 {
     fn get(&self, point: [f64; 3]) -> f64 {
-        // First, create offsets based on the input values to keep the sampled
-        // points from being near a integer boundary. This is a result of
-        // using perlin noise, which returns zero at integer boundaries.
-        let x0 = point[0] + 12414.0 / 65536.0;
-        let y0 = point[1] + 65124.0 / 65536.0;
-        let z0 = point[2] + 31337.0 / 65536.0;
-
-        let x1 = point[0] + 26519.0 / 65536.0;
-        let y1 = point[1] + 18128.0 / 65536.0;
-        let z1 = point[2] + 60943.0 / 65536.0;
-
-        let x2 = point[0] + 53820.0 / 65536.0;
-        let y2 = point[1] + 11213.0 / 65536.0;
-        let z2 = point[2] + 44845.0 / 65536.0;
-
-        let x_distort = point[0] + (self.distorters[0].get([x0, y0, z0]) * self.power);
-        let y_distort = point[1] + (self.distorters[1].get([x1, y1, z1]) * self.power);
-        let z_distort = point[2] + (self.distorters[2].get([x2, y2, z2]) * self.power);
-
-        self.source.get([x_distort, y_distort, z_distort])
+        let point = distort_3d(&self.distorters, self.power, point, |d, p| d.get(p));
+        self.source.get(point)
+    }
+}
+
+/// The distortion is computed with the filtered distorters, but the footprint is not adjusted
+/// for how much the distortion stretches or squeezes the source.
+impl<Source> FilteredNoiseFn<[f64; 3]> for Turbulence<Source>
+where
+    Source: FilteredNoiseFn<[f64; 3]>,
+{
+    fn get_filtered(&self, point: [f64; 3], filter_width: f64) -> f64 {
+        let point = distort_3d(&self.distorters, self.power, point, |d, p| {
+            d.get_filtered(p, filter_width)
+        });
+        self.source.get_filtered(point, filter_width)
     }
 }
 
+fn distort_3d<S>(distorters: &[Distorter; 4], power: f64, point: [f64; 3], sample: S) -> [f64; 3]
+where
+    S: Fn(&Distorter, [f64; 3]) -> f64,
+{
+    // First, create offsets based on the input values to keep the sampled
+    // points from being near a integer boundary. This is a result of
+    // using perlin noise, which returns zero at integer boundaries.
+    let x0 = point[0] + 12414.0 / 65536.0;
+    let y0 = point[1] + 65124.0 / 65536.0;
+    let z0 = point[2] + 31337.0 / 65536.0;
+
+    let x1 = point[0] + 26519.0 / 65536.0;
+    let y1 = point[1] + 18128.0 / 65536.0;
+    let z1 = point[2] + 60943.0 / 65536.0;
+
+    let x2 = point[0] + 53820.0 / 65536.0;
+    let y2 = point[1] + 11213.0 / 65536.0;
+    let z2 = point[2] + 44845.0 / 65536.0;
+
+    let x_distort = point[0] + (sample(&distorters[0], [x0, y0, z0]) * power);
+    let y_distort = point[1] + (sample(&distorters[1], [x1, y1, z1]) * power);
+    let z_distort = point[2] + (sample(&distorters[2], [x2, y2, z2]) * power);
+
+    [x_distort, y_distort, z_distort]
+}
+
 impl<Source> NoiseFn<[f64; 4]> for Turbulence<Source>
 where
     Source: NoiseFn<[f64; 4]>,
 {
     fn get(&self, point: [f64; 4]) -> f64 {
-        // First, create offsets based on the input values to keep the sampled
-        // points from being near a integer boundary. This is a result of
-        // using perlin noise, which returns zero at integer boundaries.
-        let x0 = point[0] + 12414.0 / 65536.0;
-        let y0 = point[1] + 65124.0 / 65536.0;
-        let z0 = point[2] + 31337.0 / 65536.0;
-        let u0 = point[3] + 57948.0 / 65536.0;
-
-        let x1 = point[0] + 26519.0 / 65536.0;
-        let y1 = point[1] + 18128.0 / 65536.0;
-        let z1 = point[2] + 60943.0 / 65536.0;
-        let u1 = point[3] + 48513.0 / 65536.0;
-
-        let x2 = point[0] + 53820.0 / 65536.0;
-        let y2 = point[1] + 11213.0 / 65536.0;
-        let z2 = point[2] + 44845.0 / 65536.0;
-        let u2 = point[3] + 39357.0 / 65536.0;
-
-        let x3 = point[0] + 18128.0 / 65536.0;
-        let y3 = point[1] + 44845.0 / 65536.0;
-        let z3 = point[2] + 12414.0 / 65536.0;
-        let u3 = point[3] + 60943.0 / 65536.0;
-
-        let x_distort = point[0] + (self.distorters[0].get([x0, y0, z0, u0]) * self.power);
-        let y_distort = point[1] + (self.distorters[1].get([x1, y1, z1, u1]) * self.power);
-        let z_distort = point[2] + (self.distorters[2].get([x2, y2, z2, u2]) * self.power);
-        let u_distort = point[3] + (self.distorters[3].get([x3, y3, z3, u3]) * self.power);
-
-        self.source
-            .get([x_distort, y_distort, z_distort, u_distort])
+        let point = distort_4d(&self.distorters, self.power, point, |d, p| d.get(p));
+        self.source.get(point)
+    }
+}
+
+/// The distortion is computed with the filtered distorters, but the footprint is not adjusted
+/// for how much the distortion stretches or squeezes the source.
+impl<Source> FilteredNoiseFn<[f64; 4]> for Turbulence<Source>
+where
+    Source: FilteredNoiseFn<[f64; 4]>,
+{
+    fn get_filtered(&self, point: [f64; 4], filter_width: f64) -> f64 {
+        let point = distort_4d(&self.distorters, self.power, point, |d, p| {
+            d.get_filtered(p, filter_width)
+        });
+        self.source.get_filtered(point, filter_width)
     }
 }
+
+fn distort_4d<S>(distorters: &[Distorter; 4], power: f64, point: [f64; 4], sample: S) -> [f64; 4]
+where
+    S: Fn(&Distorter, [f64; 4]) -> f64,
+{
+    // First, create offsets based on the input values to keep the sampled
+    // points from being near a integer boundary. This is a result of
+    // using perlin noise, which returns zero at integer boundaries.
+    let x0 = point[0] + 12414.0 / 65536.0;
+    let y0 = point[1] + 65124.0 / 65536.0;
+    let z0 = point[2] + 31337.0 / 65536.0;
+    let u0 = point[3] + 57948.0 / 65536.0;
+
+    let x1 = point[0] + 26519.0 / 65536.0;
+    let y1 = point[1] + 18128.0 / 65536.0;
+    let z1 = point[2] + 60943.0 / 65536.0;
+    let u1 = point[3] + 48513.0 / 65536.0;
+
+    let x2 = point[0] + 53820.0 / 65536.0;
+    let y2 = point[1] + 11213.0 / 65536.0;
+    let z2 = point[2] + 44845.0 / 65536.0;
+    let u2 = point[3] + 39357.0 / 65536.0;
+
+    let x3 = point[0] + 18128.0 / 65536.0;
+    let y3 = point[1] + 44845.0 / 65536.0;
+    let z3 = point[2] + 12414.0 / 65536.0;
+    let u3 = point[3] + 60943.0 / 65536.0;
+
+    let x_distort = point[0] + (sample(&distorters[0], [x0, y0, z0, u0]) * power);
+    let y_distort = point[1] + (sample(&distorters[1], [x1, y1, z1, u1]) * power);
+    let z_distort = point[2] + (sample(&distorters[2], [x2, y2, z2, u2]) * power);
+    let u_distort = point[3] + (sample(&distorters[3], [x3, y3, z3, u3]) * power);
+
+    [x_distort, y_distort, z_distort, u_distort]
+}
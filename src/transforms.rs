@@ -1,4 +1,4 @@
-use crate::{NoiseFn, SamplePoint, Seedable};
+use crate::{FilteredNoiseFn, NoiseFn, SamplePoint, Seedable};
 use num_traits::Num;
 
 pub trait PointTransform<P: SamplePoint>: Default {
@@ -29,23 +29,45 @@ impl<T: Num + Copy, const N: usize> PointTransform<[T; N]> for UniformScale<T> {
     }
 }
 
+/// Allows transforms built with the default `f64` scale to be used on single-precision points.
+impl<const N: usize> PointTransform<[f32; N]> for UniformScale<f64> {
+    fn transform(&self, point: [f32; N]) -> [f32; N] {
+        point.mul_scalar(self.scale as f32)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Transformed<Source, Transform> {
     pub source: Source,
     pub transform: Transform,
 }
 
-impl<P, S, T> NoiseFn<P> for Transformed<S, T>
+impl<P, O, S, T> NoiseFn<P, O> for Transformed<S, T>
 where
     P: SamplePoint,
-    S: NoiseFn<P>,
+    S: NoiseFn<P, O>,
     T: PointTransform<P>,
 {
-    fn get(&self, point: P) -> f64 {
+    fn get(&self, point: P) -> O {
         self.source.get(self.transform.transform(point))
     }
 }
 
+/// Scaling the point up by some amount scales the filter width up by the same amount.
+impl<P, S> FilteredNoiseFn<P> for Transformed<S, UniformScale<f64>>
+where
+    P: SamplePoint,
+    S: FilteredNoiseFn<P>,
+    UniformScale<f64>: PointTransform<P>,
+{
+    fn get_filtered(&self, point: P, filter_width: f64) -> f64 {
+        self.source.get_filtered(
+            self.transform.transform(point),
+            filter_width * self.transform.scale.abs(),
+        )
+    }
+}
+
 impl<Source, Transform> Seedable for Transformed<Source, Transform>
 where
     Source: Seedable,
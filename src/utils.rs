@@ -1,6 +1,8 @@
 #[cfg(feature = "image")]
 pub use self::image_renderer::*;
-pub use self::{color_gradient::*, noise_image::*, noise_map::*, noise_map_builder::*};
+pub use self::{
+    color_gradient::*, noise_image::*, noise_map::*, noise_map_builder::*, range_estimator::*,
+};
 
 mod color_gradient;
 #[cfg(feature = "image")]
@@ -8,3 +10,4 @@ mod image_renderer;
 mod noise_image;
 mod noise_map;
 mod noise_map_builder;
+mod range_estimator;
@@ -1,8 +1,16 @@
-use crate::{math::interpolate, utils::noise_map::NoiseMap, NoiseFn};
+use crate::{
+    fractals::{Fractal, LayerBlender},
+    math::interpolate,
+    transforms::PointTransform,
+    utils::noise_map::NoiseMap,
+    FilteredNoiseFn, NoiseFn, Seedable,
+};
 
 pub trait NoiseMapBuilder<'a> {
     fn with_size(self, width: usize, height: usize) -> Self;
 
+    /// Replaces the source module. The new module is sampled without filtering, even if the old
+    /// one was filtered.
     fn with_source_module(self, source_module: &'a dyn NoiseFn<[f64; 3]>) -> Self;
 
     fn size(&self) -> (usize, usize);
@@ -15,15 +23,41 @@ pub struct CylinderMapBuilder<'a> {
     height_bounds: (f64, f64),
     size: (usize, usize),
     source_module: &'a dyn NoiseFn<[f64; 3]>,
+    filtered_source_module: Option<&'a dyn FilteredNoiseFn<[f64; 3]>>,
 }
 
 impl<'a> CylinderMapBuilder<'a> {
+    /// Creates a builder which samples the source module without filtering. Use `new_filtered`
+    /// to filter source modules which support it.
     pub fn new(source_module: &'a dyn NoiseFn<[f64; 3]>) -> Self {
         CylinderMapBuilder {
             angle_bounds: (-90.0, 90.0),
             height_bounds: (-1.0, 1.0),
             size: (100, 100),
             source_module,
+            filtered_source_module: None,
+        }
+    }
+
+    /// Creates a builder which passes the distance between neighbouring pixels to the source
+    /// module, so that it can filter out detail which would otherwise alias.
+    pub fn new_filtered<S>(source_module: &'a S) -> Self
+    where
+        S: FilteredNoiseFn<[f64; 3]>,
+    {
+        Self::new(source_module).with_filtered_source_module(source_module)
+    }
+
+    /// Replaces the source module, and passes the distance between neighbouring pixels to it,
+    /// so that it can filter out detail which would otherwise alias.
+    pub fn with_filtered_source_module<S>(self, source_module: &'a S) -> Self
+    where
+        S: FilteredNoiseFn<[f64; 3]>,
+    {
+        CylinderMapBuilder {
+            source_module,
+            filtered_source_module: Some(source_module),
+            ..self
         }
     }
 
@@ -81,6 +115,7 @@ impl<'a> NoiseMapBuilder<'a> for CylinderMapBuilder<'a> {
     fn with_source_module(self, source_module: &'a dyn NoiseFn<[f64; 3]>) -> Self {
         CylinderMapBuilder {
             source_module,
+            filtered_source_module: None,
             ..self
         }
     }
@@ -99,6 +134,7 @@ impl<'a> NoiseMapBuilder<'a> for CylinderMapBuilder<'a> {
 
         let x_step = angle_extent / width as f64;
         let y_step = height_extent / height as f64;
+        let footprint = x_step.to_radians().abs().max(y_step.abs());
 
         for y in 0..height {
             let current_height = self.height_bounds.0 + y_step * y as f64;
@@ -109,7 +145,12 @@ impl<'a> NoiseMapBuilder<'a> for CylinderMapBuilder<'a> {
                 let point_x = current_angle.to_radians().cos();
                 let point_z = current_angle.to_radians().sin();
 
-                let value = self.source_module.get([point_x, current_height, point_z]);
+                let value = sample(
+                    self.source_module,
+                    self.filtered_source_module,
+                    [point_x, current_height, point_z],
+                    footprint,
+                );
 
                 println!(
                     "calculated value {} at {}, {}, {}",
@@ -130,9 +171,12 @@ pub struct PlaneMapBuilder<'a> {
     y_bounds: (f64, f64),
     size: (usize, usize),
     source_module: &'a dyn NoiseFn<[f64; 3]>,
+    filtered_source_module: Option<&'a dyn FilteredNoiseFn<[f64; 3]>>,
 }
 
 impl<'a> PlaneMapBuilder<'a> {
+    /// Creates a builder which samples the source module without filtering. Use `new_filtered`
+    /// to filter source modules which support it.
     pub fn new(source_module: &'a dyn NoiseFn<[f64; 3]>) -> Self {
         PlaneMapBuilder {
             is_seamless: false,
@@ -140,6 +184,29 @@ impl<'a> PlaneMapBuilder<'a> {
             y_bounds: (-1.0, 1.0),
             size: (100, 100),
             source_module,
+            filtered_source_module: None,
+        }
+    }
+
+    /// Creates a builder which passes the distance between neighbouring pixels to the source
+    /// module, so that it can filter out detail which would otherwise alias.
+    pub fn new_filtered<S>(source_module: &'a S) -> Self
+    where
+        S: FilteredNoiseFn<[f64; 3]>,
+    {
+        Self::new(source_module).with_filtered_source_module(source_module)
+    }
+
+    /// Replaces the source module, and passes the distance between neighbouring pixels to it,
+    /// so that it can filter out detail which would otherwise alias.
+    pub fn with_filtered_source_module<S>(self, source_module: &'a S) -> Self
+    where
+        S: FilteredNoiseFn<[f64; 3]>,
+    {
+        PlaneMapBuilder {
+            source_module,
+            filtered_source_module: Some(source_module),
+            ..self
         }
     }
 
@@ -184,6 +251,7 @@ impl<'a> NoiseMapBuilder<'a> for PlaneMapBuilder<'a> {
     fn with_source_module(self, source_module: &'a dyn NoiseFn<[f64; 3]>) -> Self {
         PlaneMapBuilder {
             source_module,
+            filtered_source_module: None,
             ..self
         }
     }
@@ -202,6 +270,15 @@ impl<'a> NoiseMapBuilder<'a> for PlaneMapBuilder<'a> {
 
         let x_step = x_extent / width as f64;
         let y_step = y_extent / height as f64;
+        let footprint = x_step.abs().max(y_step.abs());
+        let sample_at = |point| {
+            sample(
+                self.source_module,
+                self.filtered_source_module,
+                point,
+                footprint,
+            )
+        };
 
         for y in 0..height {
             let current_y = self.y_bounds.0 + y_step * y as f64;
@@ -210,16 +287,10 @@ impl<'a> NoiseMapBuilder<'a> for PlaneMapBuilder<'a> {
                 let current_x = self.x_bounds.0 + x_step * x as f64;
 
                 let final_value = if self.is_seamless {
-                    let sw_value = self.source_module.get([current_x, current_y, 0.0]);
-                    let se_value = self
-                        .source_module
-                        .get([current_x + x_extent, current_y, 0.0]);
-                    let nw_value = self
-                        .source_module
-                        .get([current_x, current_y + y_extent, 0.0]);
-                    let ne_value =
-                        self.source_module
-                            .get([current_x + x_extent, current_y + y_extent, 0.0]);
+                    let sw_value = sample_at([current_x, current_y, 0.0]);
+                    let se_value = sample_at([current_x + x_extent, current_y, 0.0]);
+                    let nw_value = sample_at([current_x, current_y + y_extent, 0.0]);
+                    let ne_value = sample_at([current_x + x_extent, current_y + y_extent, 0.0]);
 
                     let x_blend = 1.0 - ((current_x - self.x_bounds.0) / x_extent);
                     let y_blend = 1.0 - ((current_y - self.y_bounds.0) / y_extent);
@@ -229,7 +300,7 @@ impl<'a> NoiseMapBuilder<'a> for PlaneMapBuilder<'a> {
 
                     interpolate::linear(y0, y1, y_blend)
                 } else {
-                    self.source_module.get([current_x, current_y, 0.0])
+                    sample_at([current_x, current_y, 0.0])
                 };
 
                 result_map.with_value(x, y, final_value);
@@ -245,15 +316,41 @@ pub struct SphereMapBuilder<'a> {
     longitude_bounds: (f64, f64),
     size: (usize, usize),
     source_module: &'a dyn NoiseFn<[f64; 3]>,
+    filtered_source_module: Option<&'a dyn FilteredNoiseFn<[f64; 3]>>,
 }
 
 impl<'a> SphereMapBuilder<'a> {
+    /// Creates a builder which samples the source module without filtering. Use `new_filtered`
+    /// to filter source modules which support it.
     pub fn new(source_module: &'a dyn NoiseFn<[f64; 3]>) -> Self {
         SphereMapBuilder {
             latitude_bounds: (-1.0, 1.0),
             longitude_bounds: (-1.0, 1.0),
             size: (100, 100),
             source_module,
+            filtered_source_module: None,
+        }
+    }
+
+    /// Creates a builder which passes the distance between neighbouring pixels to the source
+    /// module, so that it can filter out detail which would otherwise alias.
+    pub fn new_filtered<S>(source_module: &'a S) -> Self
+    where
+        S: FilteredNoiseFn<[f64; 3]>,
+    {
+        Self::new(source_module).with_filtered_source_module(source_module)
+    }
+
+    /// Replaces the source module, and passes the distance between neighbouring pixels to it,
+    /// so that it can filter out detail which would otherwise alias.
+    pub fn with_filtered_source_module<S>(self, source_module: &'a S) -> Self
+    where
+        S: FilteredNoiseFn<[f64; 3]>,
+    {
+        SphereMapBuilder {
+            source_module,
+            filtered_source_module: Some(source_module),
+            ..self
         }
     }
 
@@ -305,6 +402,7 @@ impl<'a> NoiseMapBuilder<'a> for SphereMapBuilder<'a> {
     fn with_source_module(self, source_module: &'a dyn NoiseFn<[f64; 3]>) -> Self {
         SphereMapBuilder {
             source_module,
+            filtered_source_module: None,
             ..self
         }
     }
@@ -323,6 +421,8 @@ impl<'a> NoiseMapBuilder<'a> for SphereMapBuilder<'a> {
 
         let x_step = lon_extent / width as f64;
         let y_step = lat_extent / height as f64;
+        // Distance between pixels at the equator, where it is largest.
+        let footprint = x_step.abs().max(y_step.abs()).to_radians();
 
         for y in 0..height {
             let current_lat = self.latitude_bounds.0 + y_step * y as f64;
@@ -332,7 +432,14 @@ impl<'a> NoiseMapBuilder<'a> for SphereMapBuilder<'a> {
 
                 let point = lat_lon_to_xyz(current_lat, current_lon);
 
-                result_map.with_value(x, y, self.source_module.get(point));
+                let value = sample(
+                    self.source_module,
+                    self.filtered_source_module,
+                    point,
+                    footprint,
+                );
+
+                result_map.with_value(x, y, value);
             }
         }
 
@@ -340,6 +447,36 @@ impl<'a> NoiseMapBuilder<'a> for SphereMapBuilder<'a> {
     }
 }
 
+/// Renders one map for each layer of `fractal`, showing the contribution of each octave before
+/// blending.
+///
+/// `build` is called with each layer in turn, and should return the map built from it, for
+/// example `|octave| PlaneMapBuilder::new(octave).with_size(256, 256).build()`.
+pub fn build_octave_maps<B, F, T, Build>(fractal: &Fractal<B, F, T>, build: Build) -> Vec<NoiseMap>
+where
+    B: LayerBlender,
+    F: Seedable + NoiseFn<[f64; 3]>,
+    T: PointTransform<[f64; 3]>,
+    Build: Fn(&dyn NoiseFn<[f64; 3]>) -> NoiseMap,
+{
+    (0..fractal.layers().len())
+        .map(|index| build(&fractal.octave(index)))
+        .collect()
+}
+
+/// Samples the filtered source module if there is one, otherwise the source module.
+fn sample(
+    source_module: &dyn NoiseFn<[f64; 3]>,
+    filtered_source_module: Option<&dyn FilteredNoiseFn<[f64; 3]>>,
+    point: [f64; 3],
+    footprint: f64,
+) -> f64 {
+    match filtered_source_module {
+        Some(source) => source.get_filtered(point, footprint),
+        None => source_module.get(point),
+    }
+}
+
 fn lat_lon_to_xyz(lat: f64, lon: f64) -> [f64; 3] {
     let r = lat.to_radians().cos();
     let x = r * lon.to_radians().cos();
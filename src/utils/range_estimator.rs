@@ -0,0 +1,200 @@
+use crate::NoiseFn;
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+/// Estimates the output range and distribution of a noise function by sampling it.
+///
+/// The region is divided into a grid of equally sized cells, and each cell is sampled once at a
+/// random position within it. This stratified pattern covers the region more evenly than purely
+/// random samples, while avoiding the artifacts of sampling on a regular grid.
+///
+/// The results are estimates. The true minimum and maximum may lie outside of the measured ones,
+/// particularly for functions with rare extremes.
+#[derive(Clone, Copy, Debug)]
+pub struct RangeEstimator<const N: usize> {
+    /// Lower and upper bound of the region to sample along each axis.
+    pub bounds: [(f64, f64); N],
+
+    /// Approximate number of samples to take. This is rounded down to the nearest number of
+    /// the form k<sup>N</sup>.
+    pub samples: usize,
+
+    /// Seed used to place samples within their cells.
+    pub seed: u64,
+}
+
+impl<const N: usize> RangeEstimator<N> {
+    pub const DEFAULT_BOUNDS: (f64, f64) = (-128.0, 128.0);
+    pub const DEFAULT_SAMPLES: usize = 65_536;
+    pub const DEFAULT_SEED: u64 = 0;
+
+    pub fn new() -> Self {
+        Self {
+            bounds: [Self::DEFAULT_BOUNDS; N],
+            samples: Self::DEFAULT_SAMPLES,
+            seed: Self::DEFAULT_SEED,
+        }
+    }
+
+    with!(pub bounds: [(f64, f64); N]);
+    with!(pub samples: usize);
+    with!(pub seed: u64);
+
+    /// Samples `source` over the region and returns the statistics of the sampled values.
+    pub fn estimate(&self, source: &dyn NoiseFn<[f64; N]>) -> NoiseStats {
+        let cells_per_axis = cells_per_axis(self.samples, N);
+        let total = cells_per_axis.pow(N as u32);
+        let mut rng = XorShiftRng::seed_from_u64(self.seed);
+
+        let mut values = Vec::with_capacity(total);
+        for cell in 0..total {
+            let mut point = [0.0; N];
+            let mut remaining = cell;
+            for (component, &(lower, upper)) in point.iter_mut().zip(&self.bounds) {
+                let index = remaining % cells_per_axis;
+                remaining /= cells_per_axis;
+                let cell_size = (upper - lower) / cells_per_axis as f64;
+                *component = lower + (index as f64 + rng.gen::<f64>()) * cell_size;
+            }
+            values.push(source.get(point));
+        }
+
+        NoiseStats::from_values(values)
+    }
+}
+
+impl<const N: usize> Default for RangeEstimator<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the largest k with k<sup>dimensions</sup> <= samples, and at least 1.
+fn cells_per_axis(samples: usize, dimensions: usize) -> usize {
+    if dimensions == 0 {
+        return 1;
+    }
+    let mut cells = (samples as f64).powf(1.0 / dimensions as f64).round() as usize;
+    while cells > 1 && cells.saturating_pow(dimensions as u32) > samples {
+        cells -= 1;
+    }
+    cells.max(1)
+}
+
+/// Statistics of a set of values sampled from a noise function, returned by
+/// `RangeEstimator::estimate`.
+#[derive(Clone, Debug)]
+pub struct NoiseStats {
+    /// Smallest sampled value.
+    pub min: f64,
+    /// Largest sampled value.
+    pub max: f64,
+    /// Mean of the sampled values.
+    pub mean: f64,
+    /// Population variance of the sampled values.
+    pub variance: f64,
+    sorted_values: Vec<f64>,
+}
+
+impl NoiseStats {
+    /// Computes the statistics of the given values. NaN values are ignored.
+    ///
+    /// # Panics
+    /// Panics if there are no values other than NaN.
+    pub fn from_values(values: Vec<f64>) -> Self {
+        let mut sorted_values = values;
+        sorted_values.retain(|v| !v.is_nan());
+        assert!(!sorted_values.is_empty());
+        sorted_values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let count = sorted_values.len() as f64;
+        let mean = sorted_values.iter().sum::<f64>() / count;
+        let variance = sorted_values
+            .iter()
+            .map(|v| (v - mean) * (v - mean))
+            .sum::<f64>()
+            / count;
+
+        Self {
+            min: sorted_values[0],
+            max: sorted_values[sorted_values.len() - 1],
+            mean,
+            variance,
+            sorted_values,
+        }
+    }
+
+    /// Number of values the statistics were computed from.
+    pub fn count(&self) -> usize {
+        self.sorted_values.len()
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        self.variance.sqrt()
+    }
+
+    /// Returns the value below which the given fraction of the values fall, interpolating
+    /// between the nearest two values. `fraction` is clamped to [0, 1], so 0.5 gives the
+    /// median.
+    pub fn percentile(&self, fraction: f64) -> f64 {
+        let position = fraction.clamp(0.0, 1.0) * (self.sorted_values.len() - 1) as f64;
+        let lower = position.floor() as usize;
+        let upper = position.ceil() as usize;
+        let alpha = position - lower as f64;
+        self.sorted_values[lower] * (1.0 - alpha) + self.sorted_values[upper] * alpha
+    }
+
+    /// Counts how many values fall in each of `bins` equally sized bins between `min` and `max`.
+    ///
+    /// # Panics
+    /// Panics if `bins` is zero.
+    pub fn histogram(&self, bins: usize) -> Vec<usize> {
+        assert!(bins > 0);
+        let mut histogram = vec![0; bins];
+        let extent = self.max - self.min;
+        for value in &self.sorted_values {
+            let bin = if extent > 0.0 {
+                ((value - self.min) / extent * bins as f64) as usize
+            } else {
+                0
+            };
+            histogram[bin.min(bins - 1)] += 1;
+        }
+        histogram
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Constant;
+
+    #[test]
+    fn stratified_samples_cover_the_region() {
+        struct FirstAxis;
+        impl NoiseFn<[f64; 2]> for FirstAxis {
+            fn get(&self, point: [f64; 2]) -> f64 {
+                point[0]
+            }
+        }
+
+        let stats = RangeEstimator::new()
+            .with_bounds([(0.0, 1.0), (5.0, 6.0)])
+            .with_samples(10_000)
+            .estimate(&FirstAxis);
+        assert_eq!(stats.count(), 10_000);
+        assert!(stats.min >= 0.0 && stats.min < 0.01);
+        assert!(stats.max <= 1.0 && stats.max > 0.99);
+        assert!((stats.mean - 0.5).abs() < 0.01);
+        assert!((stats.percentile(0.25) - 0.25).abs() < 0.01);
+        for count in stats.histogram(4) {
+            assert!((2400..=2600).contains(&count));
+        }
+
+        let constant = RangeEstimator::<3>::new().estimate(&Constant::new(0.5));
+        assert_eq!(
+            (constant.min, constant.max, constant.variance),
+            (0.5, 0.5, 0.0)
+        );
+    }
+}